@@ -0,0 +1,77 @@
+//! Conversions to and from [chrono](https://docs.rs/chrono/0.4) types, enabled with the `chrono` feature.
+
+use crate::{Clock, PreciseClock, UtcOffset, ZonedClock};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike};
+
+impl From<PreciseClock> for NaiveTime {
+    fn from(clock: PreciseClock) -> Self {
+        // A precise clock always holds a valid time of day, below a second of nanoseconds.
+        NaiveTime::from_hms_nano_opt(clock.hour().into(), clock.minute().into(), clock.second().into(), clock.nanosecond())
+            .expect("a precise clock is always a valid time")
+    }
+}
+
+impl From<Clock> for NaiveTime {
+    fn from(clock: Clock) -> Self {
+        PreciseClock::from(clock).into()
+    }
+}
+
+/// Converts the time exactly, except for a leap second, which becomes the last nanosecond before the next minute.
+impl From<NaiveTime> for PreciseClock {
+    fn from(time: NaiveTime) -> Self {
+        // chrono represents a leap second as the 59th second with 1_000_000_000 or more nanoseconds.
+        PreciseClock::with_nanos(
+            time.hour() as i32,
+            time.minute() as i32,
+            time.second() as i32,
+            time.nanosecond().min(999_999_999).into(),
+        )
+    }
+}
+
+/// Truncates the seconds and below.
+impl From<NaiveTime> for Clock {
+    fn from(time: NaiveTime) -> Self {
+        Clock::new(time.hour() as i32, time.minute() as i32)
+    }
+}
+
+/// The local time of day, truncated to the minute.
+impl<Tz: TimeZone> From<&DateTime<Tz>> for Clock {
+    fn from(datetime: &DateTime<Tz>) -> Self {
+        datetime.time().into()
+    }
+}
+
+/// The local time of day.
+impl<Tz: TimeZone> From<&DateTime<Tz>> for PreciseClock {
+    fn from(datetime: &DateTime<Tz>) -> Self {
+        datetime.time().into()
+    }
+}
+
+/// The local time of day, truncated to the minute, with the offset in effect at that moment.
+///
+/// Offsets with seconds, such as the historical local mean times, are truncated to the minute too.
+impl<Tz: TimeZone> From<&DateTime<Tz>> for ZonedClock {
+    fn from(datetime: &DateTime<Tz>) -> Self {
+        let minutes = datetime.offset().fix().local_minus_utc() / 60;
+        let offset = UtcOffset::from_minutes(minutes).expect("chrono offsets are always below a day");
+        ZonedClock::new(datetime.time().into(), offset)
+    }
+}
+
+impl Clock {
+    /// Combines the clock with the date.
+    pub fn on(&self, date: NaiveDate) -> NaiveDateTime {
+        PreciseClock::from(self).on(date)
+    }
+}
+
+impl PreciseClock {
+    /// Combines the clock with the date.
+    pub fn on(&self, date: NaiveDate) -> NaiveDateTime {
+        date.and_time((*self).into())
+    }
+}
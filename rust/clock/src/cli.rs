@@ -0,0 +1,278 @@
+//! Commands of the `clock` calculator binary, with their text and JSON output.
+//!
+//! The binary only collects the arguments and prints the [Output](enum.Output.html) of [run](fn.run.html),
+//! so every command can be tested here without spawning a process.
+
+use crate::{Clock, ClockDuration, ClockFormat, ClockRange, UtcOffset, ZonedClock, MINUTES_PER_DAY};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+pub const USAGE: &str = "\
+Usage: clock [--json] <command> <arguments>
+
+Commands:
+    add <clock> <duration>              add the duration, e.g. clock add 23:50 +45m
+    sub <clock> <duration>              subtract the duration, e.g. clock sub 00:10 1h15m
+    diff <from> <to>                    time from one clock to the other, e.g. clock diff 22:00 06:30
+    convert <clock> <from> <to>         convert between UTC offsets, e.g. clock convert 14:00 +02:00 -05:00
+    range <start> <end> [--step <d>]    length of the range, and its clocks every <d> if given
+    between <clock> <start> <end>       whether the clock lies in the range from start to end
+    format <clock> <pattern>            format the clock, e.g. clock format 14:05 \"%I:%M %p\"
+
+Clocks are parsed like Clock::from_str, e.g. 08:30, 0830 or 8:30 pm. Durations are a signed sequence
+of hours, minutes and seconds, e.g. 45m, +1h30m or -90s; seconds are rounded down to the minute.
+With --json, the result is printed as a single-line JSON object.";
+
+/// Error of a command line which can't be run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// The command is unknown, or has the wrong number of arguments.
+    Usage(String),
+    /// An argument can't be parsed.
+    InvalidArgument { argument: String, reason: String },
+}
+
+impl CliError {
+    fn invalid<E: Display>(argument: &str, reason: E) -> Self {
+        CliError::InvalidArgument {
+            argument: argument.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}", message),
+            CliError::InvalidArgument { argument, reason } => write!(f, "invalid argument \"{}\": {}", argument, reason),
+        }
+    }
+}
+
+impl Error for CliError {}
+
+/// Result of a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    /// The clock after `add` or `sub`, with the number of days crossed.
+    Moved { clock: Clock, days: i64 },
+    /// Distances between two clocks, see [Clock::shortest_distance](../struct.Clock.html#method.shortest_distance).
+    Difference {
+        from: Clock,
+        to: Clock,
+        forward: ClockDuration,
+        shortest: ClockDuration,
+    },
+    /// The clock shown in another zone, with the day carry.
+    Converted { zoned: ZonedClock, days: i64 },
+    /// A range, with its clocks if a step was given.
+    Range { range: ClockRange, steps: Option<Vec<Clock>> },
+    Between { clock: Clock, range: ClockRange, contained: bool },
+    Formatted { clock: Clock, text: String },
+}
+
+impl Output {
+    /// The human-readable result, without the trailing newline.
+    pub fn to_text(&self) -> String {
+        match self {
+            Output::Moved { clock, days } => format!("{}{}", clock, days_suffix(*days)),
+            Output::Difference { forward, .. } => forward.to_string(),
+            Output::Converted { zoned, days } => format!("{}{}", zoned, days_suffix(*days)),
+            Output::Range { range, steps } => {
+                let mut text = format!("{} ({})", range, range.len());
+                for clock in steps.iter().flatten() {
+                    text.push('\n');
+                    text.push_str(&clock.to_string());
+                }
+                text
+            }
+            Output::Between { contained, .. } => contained.to_string(),
+            Output::Formatted { text, .. } => text.clone(),
+        }
+    }
+
+    /// The result as a single-line JSON object.
+    pub fn to_json(&self) -> String {
+        match self {
+            Output::Moved { clock, days } => format!("{{\"clock\":{},\"days\":{}}}", json_clock(*clock), days),
+            Output::Difference {
+                from,
+                to,
+                forward,
+                shortest,
+            } => format!(
+                "{{\"from\":{},\"to\":{},\"forward\":{},\"forward_minutes\":{},\"shortest\":{},\"shortest_minutes\":{}}}",
+                json_clock(*from),
+                json_clock(*to),
+                json_string(&forward.to_string()),
+                forward.minutes(),
+                json_string(&shortest.to_string()),
+                shortest.minutes()
+            ),
+            Output::Converted { zoned, days } => format!(
+                "{{\"clock\":{},\"offset\":{},\"utc\":{},\"days\":{}}}",
+                json_clock(zoned.clock()),
+                json_string(&zoned.offset().to_string()),
+                json_clock(zoned.to_utc().0),
+                days
+            ),
+            Output::Range { range, steps } => {
+                let mut json = format!(
+                    "{{\"start\":{},\"end\":{},\"length\":{},\"length_minutes\":{},\"wraps\":{}",
+                    json_clock(range.start()),
+                    json_clock(range.end()),
+                    json_string(&range.len().to_string()),
+                    range.len().minutes(),
+                    range.wraps()
+                );
+                if let Some(steps) = steps {
+                    let steps: Vec<String> = steps.iter().map(|clock| json_clock(*clock)).collect();
+                    json.push_str(&format!(",\"steps\":[{}]", steps.join(",")));
+                }
+                json.push('}');
+                json
+            }
+            Output::Between { clock, range, contained } => format!(
+                "{{\"clock\":{},\"start\":{},\"end\":{},\"contained\":{}}}",
+                json_clock(*clock),
+                json_clock(range.start()),
+                json_clock(range.end()),
+                contained
+            ),
+            Output::Formatted { clock, text } => {
+                format!("{{\"clock\":{},\"formatted\":{}}}", json_clock(*clock), json_string(text))
+            }
+        }
+    }
+}
+
+/// Runs the command given by the arguments, without the program name and the `--json` flag.
+pub fn run<S: AsRef<str>>(args: &[S]) -> Result<Output, CliError> {
+    let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+    match args.as_slice() {
+        ["add", clock, duration] => moved(parse_clock(clock)?, parse_duration(duration)?),
+        ["sub", clock, duration] => moved(parse_clock(clock)?, -parse_duration(duration)?),
+        ["diff", from, to] => {
+            let (from, to) = (parse_clock(from)?, parse_clock(to)?);
+            Ok(Output::Difference {
+                from,
+                to,
+                forward: to - from,
+                shortest: from.shortest_distance(&to),
+            })
+        }
+        ["convert", clock, from, to] => {
+            let zoned = ZonedClock::new(parse_clock(clock)?, parse_offset(from)?);
+            let (zoned, days) = zoned.to_offset(parse_offset(to)?);
+            Ok(Output::Converted { zoned, days })
+        }
+        ["range", start, end] => Ok(Output::Range {
+            range: ClockRange::new(parse_clock(start)?, parse_clock(end)?),
+            steps: None,
+        }),
+        ["range", start, end, "--step", step] => {
+            let range = ClockRange::new(parse_clock(start)?, parse_clock(end)?);
+            let minutes = parse_duration(step)?.minutes();
+            if minutes <= 0 || minutes >= MINUTES_PER_DAY {
+                return Err(CliError::invalid(step, "the step must be between 1m and 23h59m"));
+            }
+            Ok(Output::Range {
+                range,
+                steps: Some(range.step_by(minutes as u32).collect()),
+            })
+        }
+        ["between", clock, start, end] => {
+            let (clock, range) = (parse_clock(clock)?, ClockRange::new(parse_clock(start)?, parse_clock(end)?));
+            Ok(Output::Between {
+                clock,
+                range,
+                contained: range.contains(&clock),
+            })
+        }
+        ["format", clock, pattern] => {
+            let clock = parse_clock(clock)?;
+            let format = ClockFormat::new(pattern).map_err(|err| CliError::invalid(pattern, err))?;
+            Ok(Output::Formatted {
+                clock,
+                text: format.format(clock).to_string(),
+            })
+        }
+        [] => Err(CliError::Usage("missing command".to_string())),
+        [command, ..] if ["add", "sub", "diff", "convert", "range", "between", "format"].contains(command) => {
+            Err(CliError::Usage(format!("wrong arguments for \"{}\"", command)))
+        }
+        [command, ..] => Err(CliError::Usage(format!("unknown command \"{}\"", command))),
+    }
+}
+
+fn moved(clock: Clock, duration: ClockDuration) -> Result<Output, CliError> {
+    let (clock, days) = clock.overflowing_add(duration);
+    Ok(Output::Moved { clock, days })
+}
+
+fn parse_clock(argument: &str) -> Result<Clock, CliError> {
+    argument.parse().map_err(|err| CliError::invalid(argument, err))
+}
+
+fn parse_offset(argument: &str) -> Result<UtcOffset, CliError> {
+    argument.parse().map_err(|err| CliError::invalid(argument, err))
+}
+
+/// Parses a signed sequence of hours, minutes and seconds, such as `+1h30m` or `-90s`.
+fn parse_duration(argument: &str) -> Result<ClockDuration, CliError> {
+    let invalid = || CliError::invalid(argument, "expected a duration such as 45m, +1h30m or -90s");
+    let (negative, mut rest) = match argument.as_bytes().first() {
+        Some(b'-') => (true, &argument[1..]),
+        Some(b'+') => (false, &argument[1..]),
+        _ => (false, argument),
+    };
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+    let mut seconds: i64 = 0;
+    while !rest.is_empty() {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let unit = match rest[digits..].chars().next() {
+            Some('h') => 3600,
+            Some('m') => 60,
+            Some('s') => 1,
+            _ => return Err(invalid()),
+        };
+        let count: i64 = rest[..digits].parse().map_err(|_| invalid())?;
+        seconds = count
+            .checked_mul(unit)
+            .and_then(|part| seconds.checked_add(part))
+            .filter(|&seconds| seconds <= i64::MAX / 1_000_000_000)
+            .ok_or_else(invalid)?;
+        rest = &rest[digits + 1..];
+    }
+    Ok(ClockDuration::from_seconds(if negative { -seconds } else { seconds }))
+}
+
+fn days_suffix(days: i64) -> String {
+    match days {
+        0 => String::new(),
+        1 | -1 => format!(" ({:+} day)", days),
+        _ => format!(" ({:+} days)", days),
+    }
+}
+
+fn json_clock(clock: Clock) -> String {
+    json_string(&clock.to_string())
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
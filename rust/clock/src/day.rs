@@ -0,0 +1,117 @@
+use crate::duration::NANOS_PER_MINUTE;
+use crate::{Clock, ClockDuration, MINUTES_PER_DAY};
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// [Clock](struct.Clock.html) on a numbered day, for timelines spanning several days.
+///
+/// Unlike `Clock`, it never wraps around: adding minutes past midnight moves on to the next day.
+/// Day clocks are ordered by the day first, then by the time of day.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayClock {
+    day: i64,
+    clock: Clock,
+}
+
+impl DayClock {
+    pub fn new(day: i64, clock: Clock) -> Self {
+        DayClock { day, clock }
+    }
+
+    pub fn day(&self) -> i64 {
+        self.day
+    }
+
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    /// # Panics
+    /// This function will panic if the day index overflows.
+    pub fn add_minutes(&self, minutes: i64) -> Self {
+        let (clock, days) = self.clock.overflowing_add_minutes(minutes);
+        DayClock::new(self.day.checked_add(days).expect("DayClock overflow"), clock)
+    }
+
+    /// Total minutes since the midnight starting day 0.
+    ///
+    /// # Panics
+    /// This function will panic if the result doesn't fit in `i64`.
+    pub fn total_minutes(&self) -> i64 {
+        self.day
+            .checked_mul(MINUTES_PER_DAY)
+            .and_then(|minutes| minutes.checked_add(self.clock.minutes_since_midnight().into()))
+            .expect("DayClock overflow")
+    }
+}
+
+/// Midnight on day 0.
+impl Default for DayClock {
+    fn default() -> Self {
+        DayClock::new(0, Clock::MIDNIGHT)
+    }
+}
+
+impl From<Clock> for DayClock {
+    /// The clock on day 0.
+    fn from(clock: Clock) -> Self {
+        DayClock::new(0, clock)
+    }
+}
+
+impl Add<i64> for DayClock {
+    type Output = Self;
+    fn add(self, minutes: i64) -> Self {
+        self.add_minutes(minutes)
+    }
+}
+
+impl Sub<i64> for DayClock {
+    type Output = Self;
+    fn sub(self, minutes: i64) -> Self {
+        self.add_minutes(minutes.checked_neg().expect("DayClock overflow"))
+    }
+}
+
+impl AddAssign<i64> for DayClock {
+    fn add_assign(&mut self, minutes: i64) {
+        *self = *self + minutes;
+    }
+}
+
+impl SubAssign<i64> for DayClock {
+    fn sub_assign(&mut self, minutes: i64) {
+        *self = *self - minutes;
+    }
+}
+
+/// Adds the duration, rounding down to the whole minute.
+impl Add<ClockDuration> for DayClock {
+    type Output = Self;
+    fn add(self, duration: ClockDuration) -> Self {
+        self.add_minutes(duration.as_nanos().div_euclid(NANOS_PER_MINUTE))
+    }
+}
+
+/// Subtracts the duration, rounding down to the whole minute.
+impl Sub<ClockDuration> for DayClock {
+    type Output = Self;
+    fn sub(self, duration: ClockDuration) -> Self {
+        self + -duration
+    }
+}
+
+/// Signed time from `other` to `self`, negative if `other` is later.
+impl Sub for DayClock {
+    type Output = ClockDuration;
+    fn sub(self, other: DayClock) -> ClockDuration {
+        ClockDuration::from_minutes(self.total_minutes() - other.total_minutes())
+    }
+}
+
+/// Formats the day clock as `day 2 08:30`.
+impl Display for DayClock {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "day {} {}", self.day, self.clock())
+    }
+}
@@ -0,0 +1,175 @@
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, Neg, Sub};
+use core::time::Duration;
+
+pub(crate) const NANOS_PER_SECOND: i64 = 1_000_000_000;
+pub(crate) const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SECOND;
+
+/// Signed span of time between two clock readings.
+///
+/// Unlike [Duration](https://doc.rust-lang.org/std/time/struct.Duration.html), it can be negative,
+/// which is what the wrap-aware differences between clocks need.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClockDuration {
+    nanos: i64,
+}
+
+impl ClockDuration {
+    pub const ZERO: ClockDuration = ClockDuration { nanos: 0 };
+
+    /// # Panics
+    /// This function will panic if the duration doesn't fit in the supported range of about 292 years.
+    pub fn from_minutes(minutes: i64) -> Self {
+        Self::from_scaled(minutes, NANOS_PER_MINUTE)
+    }
+
+    /// # Panics
+    /// This function will panic if the duration doesn't fit in the supported range of about 292 years.
+    pub fn from_seconds(seconds: i64) -> Self {
+        Self::from_scaled(seconds, NANOS_PER_SECOND)
+    }
+
+    pub const fn from_nanos(nanos: i64) -> Self {
+        ClockDuration { nanos }
+    }
+
+    fn from_scaled(count: i64, scale: i64) -> Self {
+        ClockDuration {
+            nanos: count.checked_mul(scale).expect("ClockDuration overflow"),
+        }
+    }
+
+    /// Whole minutes in the duration, rounded towards zero.
+    pub fn minutes(self) -> i64 {
+        self.nanos / NANOS_PER_MINUTE
+    }
+
+    /// Whole seconds in the duration, rounded towards zero.
+    pub fn seconds(self) -> i64 {
+        self.nanos / NANOS_PER_SECOND
+    }
+
+    /// Total nanoseconds in the duration.
+    pub const fn as_nanos(self) -> i64 {
+        self.nanos
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.nanos < 0
+    }
+
+    /// # Panics
+    /// This function will panic for the most negative duration, whose absolute value doesn't fit.
+    pub fn abs(self) -> Self {
+        self.checked_abs().expect("ClockDuration overflow")
+    }
+
+    /// The absolute value, or None for the most negative duration.
+    pub fn checked_abs(self) -> Option<Self> {
+        self.nanos.checked_abs().map(ClockDuration::from_nanos)
+    }
+
+    /// The negated duration, or None for the most negative duration.
+    pub fn checked_neg(self) -> Option<Self> {
+        self.nanos.checked_neg().map(ClockDuration::from_nanos)
+    }
+}
+
+/// # Panics
+/// This function will panic for the most negative duration, whose negation doesn't fit.
+impl Neg for ClockDuration {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.checked_neg().expect("ClockDuration overflow")
+    }
+}
+
+impl Add for ClockDuration {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        ClockDuration {
+            nanos: self.nanos + other.nanos,
+        }
+    }
+}
+
+impl Sub for ClockDuration {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        ClockDuration {
+            nanos: self.nanos - other.nanos,
+        }
+    }
+}
+
+/// Formats the duration as hours, minutes and seconds, omitting the zero components,
+/// e.g. `1h55m`, `-5m`, `1m30s` or `0m`.
+impl Display for ClockDuration {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        let nanos = self.nanos.unsigned_abs();
+        let (seconds, subsec) = (nanos / NANOS_PER_SECOND as u64, nanos % NANOS_PER_SECOND as u64);
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        if hours > 0 {
+            write!(f, "{}h", hours)?;
+        }
+        if minutes > 0 || (hours == 0 && seconds == 0 && subsec == 0) {
+            write!(f, "{}m", minutes)?;
+        }
+        match (seconds, subsec) {
+            (0, 0) => Ok(()),
+            (seconds, 0) => write!(f, "{}s", seconds),
+            (seconds, mut subsec) => {
+                // Drops the trailing zeros of the fraction.
+                let mut digits = 9;
+                while subsec % 10 == 0 {
+                    subsec /= 10;
+                    digits -= 1;
+                }
+                write!(f, "{}.{:0digits$}s", seconds, subsec, digits = digits)
+            }
+        }
+    }
+}
+
+/// Error returned when converting between [ClockDuration](struct.ClockDuration.html) and `std::time::Duration` fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationError {
+    /// `std::time::Duration` can't be negative.
+    Negative,
+    /// The duration is too long for a `ClockDuration`.
+    OutOfRange,
+}
+
+impl Display for DurationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DurationError::Negative => write!(f, "negative durations can't be represented"),
+            DurationError::OutOfRange => write!(f, "duration is out of the supported range"),
+        }
+    }
+}
+
+impl Error for DurationError {}
+
+impl TryFrom<ClockDuration> for Duration {
+    type Error = DurationError;
+    fn try_from(duration: ClockDuration) -> Result<Self, DurationError> {
+        u64::try_from(duration.nanos)
+            .map(Duration::from_nanos)
+            .map_err(|_| DurationError::Negative)
+    }
+}
+
+impl TryFrom<Duration> for ClockDuration {
+    type Error = DurationError;
+    fn try_from(duration: Duration) -> Result<Self, DurationError> {
+        i64::try_from(duration.as_nanos())
+            .map(|nanos| ClockDuration { nanos })
+            .map_err(|_| DurationError::OutOfRange)
+    }
+}
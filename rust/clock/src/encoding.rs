@@ -0,0 +1,333 @@
+//! Encodings of clocks for display hardware: binary and BCD, Roman numerals and the word clock, behind the
+//! common [Renderer](trait.Renderer.html) trait.
+//!
+//! Every renderer decodes its output back, which gives the same clock; decoding rejects anything
+//! the renderer can't produce.
+
+use crate::{Clock, HOURS_PER_DAY, MINUTES_PER_HOUR};
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// Renders clocks in some encoding, and decodes them back.
+pub trait Renderer {
+    type Output;
+
+    fn render(&self, clock: Clock) -> Self::Output;
+
+    /// The clock rendered as `output`, or an error if the renderer can't produce it.
+    fn decode(&self, output: &Self::Output) -> Result<Clock, DecodeError>;
+}
+
+/// Error returned when an output can't be decoded back into a clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The output isn't valid in the encoding, e.g. a BCD digit above 9 or a Roman numeral out of order.
+    Malformed,
+    /// The output is valid, but its hour or minute isn't, e.g. 25 hours.
+    OutOfRange,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DecodeError::Malformed => write!(f, "malformed clock encoding"),
+            DecodeError::OutOfRange => write!(f, "encoded clock out of range"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+fn clock_from(hour: u32, minute: u32) -> Result<Clock, DecodeError> {
+    if hour < HOURS_PER_DAY as u32 && minute < MINUTES_PER_HOUR as u32 {
+        Ok(Clock::from_hm(hour as u8, minute as u8))
+    } else {
+        Err(DecodeError::OutOfRange)
+    }
+}
+
+/// Binary clock: a row of six bits for the hour and another for the minute, most significant bit first,
+/// e.g. 13:45 lights `001101` and `101101`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Binary;
+
+impl Binary {
+    fn bits(value: u8) -> [bool; 6] {
+        let mut bits = [false; 6];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = (value >> (5 - i)) & 1 == 1;
+        }
+        bits
+    }
+
+    fn value(bits: &[bool; 6]) -> u32 {
+        bits.iter().fold(0, |value, &bit| (value << 1) | u32::from(bit))
+    }
+}
+
+impl Renderer for Binary {
+    type Output = [[bool; 6]; 2];
+
+    fn render(&self, clock: Clock) -> Self::Output {
+        [Self::bits(clock.hour()), Self::bits(clock.minute())]
+    }
+
+    fn decode(&self, output: &Self::Output) -> Result<Clock, DecodeError> {
+        clock_from(Self::value(&output[0]), Self::value(&output[1]))
+    }
+}
+
+/// Packed binary-coded decimal: a byte for the hour and another for the minute, holding a decimal digit
+/// in each half, e.g. 13:45 is `[0x13, 0x45]`, as in the registers of real-time clock chips.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bcd;
+
+impl Bcd {
+    fn byte(value: u8) -> u8 {
+        ((value / 10) << 4) | (value % 10)
+    }
+
+    fn value(byte: u8) -> Result<u32, DecodeError> {
+        let (tens, ones) = (byte >> 4, byte & 0x0f);
+        if tens > 9 || ones > 9 {
+            return Err(DecodeError::Malformed);
+        }
+        Ok(u32::from(tens * 10 + ones))
+    }
+}
+
+impl Renderer for Bcd {
+    type Output = [u8; 2];
+
+    fn render(&self, clock: Clock) -> Self::Output {
+        [Self::byte(clock.hour()), Self::byte(clock.minute())]
+    }
+
+    fn decode(&self, output: &Self::Output) -> Result<Clock, DecodeError> {
+        clock_from(Self::value(output[0])?, Self::value(output[1])?)
+    }
+}
+
+/// Roman numerals for the hour and the minute, with the medieval `N` for zero, e.g. `XIII:XLV` or `N:V`.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Roman;
+
+#[cfg(feature = "std")]
+const ROMAN_NUMERALS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+#[cfg(feature = "std")]
+impl Roman {
+    fn numeral(mut value: u32) -> String {
+        if value == 0 {
+            return "N".to_string();
+        }
+        let mut numeral = String::new();
+        for &(step, symbols) in &ROMAN_NUMERALS {
+            while value >= step {
+                numeral.push_str(symbols);
+                value -= step;
+            }
+        }
+        numeral
+    }
+
+    /// Reads a numeral in the canonical form only, so that `IIII` or `VX` are rejected.
+    fn value(numeral: &str) -> Result<u32, DecodeError> {
+        let mut value: u32 = 0;
+        let mut rest = numeral;
+        for &(step, symbols) in &ROMAN_NUMERALS {
+            while let Some(tail) = rest.strip_prefix(symbols) {
+                value = value.checked_add(step).ok_or(DecodeError::Malformed)?;
+                rest = tail;
+            }
+        }
+        if numeral == "N" {
+            Ok(0)
+        } else if rest.is_empty() && !numeral.is_empty() && Self::numeral(value) == numeral {
+            Ok(value)
+        } else {
+            Err(DecodeError::Malformed)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Renderer for Roman {
+    type Output = String;
+
+    fn render(&self, clock: Clock) -> String {
+        format!("{}:{}", Self::numeral(clock.hour().into()), Self::numeral(clock.minute().into()))
+    }
+
+    fn decode(&self, output: &String) -> Result<Clock, DecodeError> {
+        let mut parts = output.split(':');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(hour), Some(minute), None) => clock_from(Self::value(hour)?, Self::value(minute)?),
+            _ => Err(DecodeError::Malformed),
+        }
+    }
+}
+
+/// Word clock: an 11 by 10 grid of letters spelling the time in English, e.g. "IT IS TWENTY FIVE PAST EIGHT PM",
+/// with four extra dots for the minutes past the five-minute mark.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordClock;
+
+/// Highlight mask of the [WordClock](struct.WordClock.html) grid, and the number of minute dots lit, from 0 to 4.
+///
+/// Displays as the grid with the unlit letters replaced by dots, followed by a line with the minute dots.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WordMask {
+    pub cells: [[bool; WordClock::WIDTH]; WordClock::HEIGHT],
+    pub dots: u8,
+}
+
+/// A word of the grid: its row, first column and length.
+type Word = (usize, usize, usize);
+
+const IT: Word = (0, 0, 2);
+const IS: Word = (0, 3, 2);
+const AM: Word = (0, 7, 2);
+const PM: Word = (0, 9, 2);
+const A: Word = (1, 0, 1);
+const QUARTER: Word = (1, 2, 7);
+const TWENTY: Word = (2, 0, 6);
+const FIVE_MINUTES: Word = (2, 6, 4);
+const HALF: Word = (3, 0, 4);
+const TEN_MINUTES: Word = (3, 5, 3);
+const TO: Word = (3, 9, 2);
+const PAST: Word = (4, 0, 4);
+const OCLOCK: Word = (9, 5, 6);
+
+/// The words of the hours from one to twelve.
+const HOURS: [Word; 12] = [
+    (5, 0, 3),
+    (6, 8, 3),
+    (5, 6, 5),
+    (6, 0, 4),
+    (6, 4, 4),
+    (5, 3, 3),
+    (8, 0, 5),
+    (7, 0, 5),
+    (4, 7, 4),
+    (9, 0, 3),
+    (7, 5, 6),
+    (8, 5, 6),
+];
+
+/// The words for the minutes past the hour, every five minutes; the ones from 35 on count to the next hour.
+const MINUTE_WORDS: [&[Word]; 12] = [
+    &[OCLOCK],
+    &[FIVE_MINUTES, PAST],
+    &[TEN_MINUTES, PAST],
+    &[A, QUARTER, PAST],
+    &[TWENTY, PAST],
+    &[TWENTY, FIVE_MINUTES, PAST],
+    &[HALF, PAST],
+    &[TWENTY, FIVE_MINUTES, TO],
+    &[TWENTY, TO],
+    &[A, QUARTER, TO],
+    &[TEN_MINUTES, TO],
+    &[FIVE_MINUTES, TO],
+];
+
+impl WordClock {
+    pub const WIDTH: usize = 11;
+    pub const HEIGHT: usize = 10;
+
+    /// The letters of the grid, row by row.
+    pub const GRID: [&'static str; WordClock::HEIGHT] = [
+        "ITLISASAMPM",
+        "ACQUARTERDC",
+        "TWENTYFIVEX",
+        "HALFSTENFTO",
+        "PASTERUNINE",
+        "ONESIXTHREE",
+        "FOURFIVETWO",
+        "EIGHTELEVEN",
+        "SEVENTWELVE",
+        "TENSEOCLOCK",
+    ];
+
+    fn light(mask: &mut WordMask, (row, start, length): Word) {
+        for cell in &mut mask.cells[row][start..start + length] {
+            *cell = true;
+        }
+    }
+
+    fn is_lit(mask: &WordMask, (row, start, length): Word) -> bool {
+        mask.cells[row][start..start + length].iter().all(|&cell| cell)
+    }
+}
+
+impl Renderer for WordClock {
+    type Output = WordMask;
+
+    fn render(&self, clock: Clock) -> WordMask {
+        let mut mask = WordMask {
+            dots: clock.minute() % 5,
+            ..WordMask::default()
+        };
+        let five_minutes = usize::from(clock.minute() / 5);
+        // From 35 minutes on, the time is told to the next hour.
+        let hour = (usize::from(clock.hour()) + usize::from(five_minutes >= 7)) % 12;
+        let meridiem = if clock.hour() < 12 { AM } else { PM };
+        for &word in [IT, IS, meridiem, HOURS[(hour + 11) % 12]].iter().chain(MINUTE_WORDS[five_minutes]) {
+            Self::light(&mut mask, word);
+        }
+        mask
+    }
+
+    fn decode(&self, mask: &WordMask) -> Result<Clock, DecodeError> {
+        if mask.dots > 4 {
+            return Err(DecodeError::OutOfRange);
+        }
+        // Some phrases contain others, e.g. "twenty five past" contains "twenty past", so the longest one wins.
+        let five_minutes = (0..MINUTE_WORDS.len())
+            .filter(|&i| MINUTE_WORDS[i].iter().all(|&word| Self::is_lit(mask, word)))
+            .max_by_key(|&i| MINUTE_WORDS[i].len())
+            .ok_or(DecodeError::Malformed)?;
+        let hour = HOURS.iter().position(|&word| Self::is_lit(mask, word)).ok_or(DecodeError::Malformed)?;
+        let pm = Self::is_lit(mask, PM);
+        // The hour told, from one to twelve, is the next one from 35 minutes on.
+        let hour = (hour + 1 + 12 - usize::from(five_minutes >= 7)) % 12 + if pm { 12 } else { 0 };
+        let minute = u32::from(mask.dots) + 5 * five_minutes as u32;
+        let clock = clock_from(hour as u32, minute)?;
+        // Anything lit beyond the words of the time, such as a second hour, makes the mask invalid.
+        if self.render(clock) == *mask {
+            Ok(clock)
+        } else {
+            Err(DecodeError::Malformed)
+        }
+    }
+}
+
+impl Display for WordMask {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (row, letters) in self.cells.iter().zip(WordClock::GRID.iter()) {
+            for (&lit, letter) in row.iter().zip(letters.chars()) {
+                write!(f, "{}", if lit { letter } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        for dot in 0..4 {
+            write!(f, "{}", if dot < self.dots { '*' } else { '.' })?;
+        }
+        Ok(())
+    }
+}
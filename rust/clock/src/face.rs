@@ -0,0 +1,203 @@
+//! Analog clock faces: the angles of the hands, and renderers drawing them as SVG or ASCII art.
+//!
+//! The renderers round every coordinate, so the same clock always gives the same output.
+
+use crate::{Clock, PreciseClock};
+use std::fmt::Write;
+
+/// Angles of the clock hands in degrees, measured clockwise from 12 o'clock, in the range `[0, 360)`.
+///
+/// The hands sweep continuously, e.g. at 08:30 the hour hand is halfway between 8 and 9.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HandAngles {
+    pub hour: f64,
+    pub minute: f64,
+    pub second: f64,
+}
+
+impl HandAngles {
+    pub fn of<C: Into<PreciseClock>>(clock: C) -> Self {
+        let clock = clock.into();
+        let seconds = f64::from(clock.second()) + f64::from(clock.nanosecond()) / 1e9;
+        let minutes = f64::from(clock.minute()) + seconds / 60.0;
+        let hours = f64::from(clock.hour() % 12) + minutes / 60.0;
+        HandAngles {
+            hour: hours * 30.0,
+            minute: minutes * 6.0,
+            second: seconds * 6.0,
+        }
+    }
+
+    /// The smaller angle between the hour and minute hands, from 0 to 180 degrees.
+    pub fn between_hour_and_minute(&self) -> f64 {
+        angle_between(self.hour, self.minute)
+    }
+
+    /// The smaller angle between the minute and second hands, from 0 to 180 degrees.
+    pub fn between_minute_and_second(&self) -> f64 {
+        angle_between(self.minute, self.second)
+    }
+}
+
+/// The smaller angle between two directions given in degrees, from 0 to 180.
+pub fn angle_between(a: f64, b: f64) -> f64 {
+    let difference = (a - b).rem_euclid(360.0);
+    difference.min(360.0 - difference)
+}
+
+impl Clock {
+    pub fn hand_angles(&self) -> HandAngles {
+        HandAngles::of(*self)
+    }
+}
+
+impl PreciseClock {
+    pub fn hand_angles(&self) -> HandAngles {
+        HandAngles::of(*self)
+    }
+}
+
+/// Offset of the point at `length` from the center in the direction of `angle`, with `y` growing downwards.
+fn polar(angle: f64, length: f64) -> (f64, f64) {
+    let radians = angle.to_radians();
+    (length * radians.sin(), -length * radians.cos())
+}
+
+/// Formats the coordinate with at most two decimals, and without a negative zero.
+fn coordinate(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        rounded.to_string()
+    }
+}
+
+/// SVG clock face with hour ticks and hands, e.g. `SvgFace::new(200).render(Clock::new(8, 30))`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SvgFace {
+    size: u32,
+    seconds: bool,
+}
+
+impl SvgFace {
+    /// A square face `size` pixels wide, without the second hand.
+    pub fn new(size: u32) -> Self {
+        SvgFace { size, seconds: false }
+    }
+
+    /// Shows or hides the second hand.
+    pub fn with_seconds(self, seconds: bool) -> Self {
+        SvgFace { seconds, ..self }
+    }
+
+    pub fn render<C: Into<PreciseClock>>(&self, clock: C) -> String {
+        let angles = HandAngles::of(clock);
+        let center = f64::from(self.size) / 2.0;
+        let radius = center * 0.95;
+        let c = coordinate(center);
+        let mut svg = String::new();
+        // Writing into a String can't fail.
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{0}" viewBox="0 0 {0} {0}">"#,
+            self.size
+        );
+        let _ = writeln!(
+            svg,
+            r#"  <circle cx="{0}" cy="{0}" r="{1}" fill="white" stroke="black" stroke-width="2"/>"#,
+            c,
+            coordinate(radius)
+        );
+        for hour in 0..12 {
+            let angle = f64::from(hour) * 30.0;
+            let (x1, y1) = polar(angle, radius * 0.85);
+            let (x2, y2) = polar(angle, radius);
+            let _ = writeln!(
+                svg,
+                r#"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="black" stroke-width="2"/>"#,
+                coordinate(center + x1),
+                coordinate(center + y1),
+                coordinate(center + x2),
+                coordinate(center + y2)
+            );
+        }
+        let mut hands = vec![("hour", angles.hour, 0.5, 6, "black"), ("minute", angles.minute, 0.8, 4, "black")];
+        if self.seconds {
+            hands.push(("second", angles.second, 0.9, 1, "red"));
+        }
+        for (class, angle, length, width, color) in hands {
+            let (x, y) = polar(angle, radius * length);
+            let _ = writeln!(
+                svg,
+                r#"  <line class="{class}" x1="{c}" y1="{c}" x2="{x}" y2="{y}" stroke="{color}" stroke-width="{width}" stroke-linecap="round"/>"#,
+                class = class,
+                c = c,
+                x = coordinate(center + x),
+                y = coordinate(center + y),
+                color = color,
+                width = width
+            );
+        }
+        let _ = writeln!(svg, r#"  <circle cx="{0}" cy="{0}" r="3" fill="black"/>"#, c);
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// ASCII-art clock face, drawing the dial with `.`, the hour hand with `h` and the minute hand with `m`.
+///
+/// Each cell is about twice as tall as it is wide, so the face is `4 * radius + 1` characters wide
+/// and `2 * radius + 1` lines tall.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AsciiFace {
+    radius: usize,
+}
+
+impl AsciiFace {
+    /// # Panics
+    /// This function will panic if `radius` is below 3, which leaves no room for the hands.
+    pub fn new(radius: usize) -> Self {
+        assert!(radius >= 3, "ASCII face radius must be at least 3");
+        AsciiFace { radius }
+    }
+
+    pub fn render<C: Into<PreciseClock>>(&self, clock: C) -> String {
+        let angles = HandAngles::of(clock);
+        let radius = self.radius as f64;
+        let (width, height) = (4 * self.radius + 1, 2 * self.radius + 1);
+        let mut grid = vec![vec![' '; width]; height];
+        // Maps an offset from the center, in rows, to a cell.
+        let cell = |(x, y): (f64, f64)| ((radius + y).round() as usize, (2.0 * (radius + x)).round() as usize);
+        for (row, line) in grid.iter_mut().enumerate() {
+            for (column, c) in line.iter_mut().enumerate() {
+                let (x, y) = (column as f64 / 2.0 - radius, row as f64 - radius);
+                if ((x * x + y * y).sqrt() - radius).abs() < 0.5 {
+                    *c = '.';
+                }
+            }
+        }
+        for &(label, angle) in &[("12", 0.0), ("3", 90.0), ("6", 180.0), ("9", 270.0)] {
+            let (row, column) = cell(polar(angle, radius));
+            let start = column.saturating_sub(label.len() / 2).min(width - label.len());
+            for (i, c) in label.chars().enumerate() {
+                grid[row][start + i] = c;
+            }
+        }
+        for &(mark, angle, length) in &[('h', angles.hour, 0.5), ('m', angles.minute, 0.8)] {
+            let steps = (4.0 * radius * length).ceil() as usize;
+            for step in 1..=steps {
+                let (row, column) = cell(polar(angle, radius * length * step as f64 / steps as f64));
+                grid[row][column] = mark;
+            }
+        }
+        grid[self.radius][2 * self.radius] = 'o';
+        let mut ascii = String::new();
+        for line in grid {
+            let line: String = line.into_iter().collect();
+            ascii.push_str(line.trim_end());
+            ascii.push('\n');
+        }
+        ascii
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{hour12, Clock, PreciseClock};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Error returned for an invalid [ClockFormat](struct.ClockFormat.html) pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatError {
+    position: usize,
+}
+
+impl FormatError {
+    /// Byte offset of the `%` which starts the invalid specifier.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "invalid format specifier at position {}", self.position)
+    }
+}
+
+impl Error for FormatError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pad {
+    Zero,
+    Space,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Literal(String),
+    Hour24(Pad),
+    Hour12(Pad),
+    Minute(Pad),
+    Second(Pad),
+    /// Fraction of the second with the given number of digits.
+    Fraction(usize),
+    Meridiem { upper: bool },
+}
+
+/// Compiled strftime-like pattern, which can be reused to format many clocks.
+///
+/// The supported specifiers are:
+///
+/// | Specifier | Meaning                                   | Example |
+/// |-----------|-------------------------------------------|---------|
+/// | `%H`      | hour of the day, `00` to `23`             | `08`    |
+/// | `%k`      | hour of the day, space-padded             | ` 8`    |
+/// | `%I`      | hour on the 12-hour clock, `01` to `12`   | `08`    |
+/// | `%l`      | hour on the 12-hour clock, space-padded   | ` 8`    |
+/// | `%M`      | minute, `00` to `59`                      | `05`    |
+/// | `%S`      | second, `00` to `59`                      | `09`    |
+/// | `%f`      | nanoseconds, 9 digits; `%3f` and `%6f` give milli- and microseconds | `250` |
+/// | `%p`      | `AM` or `PM`                              | `PM`    |
+/// | `%P`      | `am` or `pm`                              | `pm`    |
+/// | `%R`      | same as `%H:%M`                           | `08:05` |
+/// | `%T`      | same as `%H:%M:%S`                        | `08:05:09` |
+/// | `%%`      | literal `%`                               | `%`     |
+///
+/// The numeric specifiers accept the padding flags `-` (no padding), `_` (spaces) and `0` (zeros),
+/// e.g. `%-I` gives `8`. Anything else is copied as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClockFormat {
+    items: Vec<Item>,
+}
+
+impl ClockFormat {
+    pub fn new(pattern: &str) -> Result<Self, FormatError> {
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut chars = pattern.char_indices();
+        while let Some((position, c)) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+            let error = FormatError { position };
+            let mut next = || chars.next().map(|(_, c)| c).ok_or(error);
+            let mut specifier = next()?;
+            let pad = match specifier {
+                '-' => Some(Pad::None),
+                '_' => Some(Pad::Space),
+                '0' => Some(Pad::Zero),
+                _ => None,
+            };
+            if pad.is_some() {
+                specifier = next()?;
+            }
+            let digits = match specifier {
+                '3' | '6' | '9' if pad.is_none() => {
+                    let digits = specifier.to_digit(10).unwrap_or(9) as usize;
+                    specifier = next()?;
+                    if specifier != 'f' {
+                        return Err(error);
+                    }
+                    Some(digits)
+                }
+                _ => None,
+            };
+            let item = match (specifier, pad) {
+                ('%', None) => {
+                    literal.push('%');
+                    continue;
+                }
+                ('H', pad) => Item::Hour24(pad.unwrap_or(Pad::Zero)),
+                ('k', pad) => Item::Hour24(pad.unwrap_or(Pad::Space)),
+                ('I', pad) => Item::Hour12(pad.unwrap_or(Pad::Zero)),
+                ('l', pad) => Item::Hour12(pad.unwrap_or(Pad::Space)),
+                ('M', pad) => Item::Minute(pad.unwrap_or(Pad::Zero)),
+                ('S', pad) => Item::Second(pad.unwrap_or(Pad::Zero)),
+                ('f', None) => Item::Fraction(digits.unwrap_or(9)),
+                ('p', None) => Item::Meridiem { upper: true },
+                ('P', None) => Item::Meridiem { upper: false },
+                ('R', None) | ('T', None) => {
+                    flush(&mut items, &mut literal);
+                    items.push(Item::Hour24(Pad::Zero));
+                    items.push(Item::Literal(":".to_string()));
+                    items.push(Item::Minute(Pad::Zero));
+                    if specifier == 'T' {
+                        items.push(Item::Literal(":".to_string()));
+                        items.push(Item::Second(Pad::Zero));
+                    }
+                    continue;
+                }
+                _ => return Err(error),
+            };
+            flush(&mut items, &mut literal);
+            items.push(item);
+        }
+        flush(&mut items, &mut literal);
+        Ok(ClockFormat { items })
+    }
+
+    /// Applies the format to the clock, returning a value which can be displayed or turned into a string.
+    pub fn format<C: Into<PreciseClock>>(&self, clock: C) -> Formatted<'_> {
+        Formatted {
+            format: self,
+            clock: clock.into(),
+        }
+    }
+}
+
+fn flush(items: &mut Vec<Item>, literal: &mut String) {
+    if !literal.is_empty() {
+        items.push(Item::Literal(std::mem::take(literal)));
+    }
+}
+
+impl FromStr for ClockFormat {
+    type Err = FormatError;
+    fn from_str(pattern: &str) -> Result<Self, FormatError> {
+        ClockFormat::new(pattern)
+    }
+}
+
+/// Clock together with the format to display it in, returned by [ClockFormat::format](struct.ClockFormat.html#method.format).
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'a> {
+    format: &'a ClockFormat,
+    clock: PreciseClock,
+}
+
+fn write_number(f: &mut Formatter, value: u32, pad: Pad) -> fmt::Result {
+    match pad {
+        Pad::Zero => write!(f, "{:02}", value),
+        Pad::Space => write!(f, "{:2}", value),
+        Pad::None => write!(f, "{}", value),
+    }
+}
+
+impl Display for Formatted<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let clock = &self.clock;
+        for item in &self.format.items {
+            match *item {
+                Item::Literal(ref text) => f.write_str(text)?,
+                Item::Hour24(pad) => write_number(f, clock.hour().into(), pad)?,
+                Item::Hour12(pad) => write_number(f, hour12(clock.hour()), pad)?,
+                Item::Minute(pad) => write_number(f, clock.minute().into(), pad)?,
+                Item::Second(pad) => write_number(f, clock.second().into(), pad)?,
+                Item::Fraction(digits) => {
+                    let value = clock.nanosecond() / 10u32.pow(9 - digits as u32);
+                    write!(f, "{:0width$}", value, width = digits)?
+                }
+                Item::Meridiem { upper } => {
+                    let text = match (clock.hour() < 12, upper) {
+                        (true, true) => "AM",
+                        (false, true) => "PM",
+                        (true, false) => "am",
+                        (false, false) => "pm",
+                    };
+                    f.write_str(text)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Clock {
+    /// Formats the clock with a strftime-like pattern, see [ClockFormat](struct.ClockFormat.html) for the specifiers.
+    ///
+    /// Compile the pattern with `ClockFormat::new` instead when formatting many clocks.
+    pub fn format(&self, pattern: &str) -> Result<String, FormatError> {
+        PreciseClock::from(self).format(pattern)
+    }
+}
+
+impl PreciseClock {
+    /// Formats the clock with a strftime-like pattern, see [ClockFormat](struct.ClockFormat.html) for the specifiers.
+    pub fn format(&self, pattern: &str) -> Result<String, FormatError> {
+        Ok(ClockFormat::new(pattern)?.format(*self).to_string())
+    }
+}
@@ -1,70 +1,337 @@
-use std::ops::Add;
-use std::fmt::{self, Formatter, Display};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct Hours(u8);
+//! Times of day which wrap around at midnight, with durations, ranges, time zones and other dials.
+//!
+//! The crate is `no_std` at its core: [Clock](struct.Clock.html), [PreciseClock](struct.PreciseClock.html),
+//! [ClockDuration](struct.ClockDuration.html), [ClockRange](struct.ClockRange.html), [ZonedClock](struct.ZonedClock.html),
+//! [DayClock](struct.DayClock.html), [ModularClock](struct.ModularClock.html), the [Stopwatch](struct.Stopwatch.html)
+//! and the parsing never allocate; the system time source needs the standard library.
+//! Custom formats, schedules, spoken time, Roman numerals and clock faces need the "std" feature, enabled by default.
+//!
+//! The `clock` binary is a calculator over the crate for the shell, e.g. `clock add 23:50 +45m`;
+//! its commands live in the [cli](cli/index.html) module.
 
-impl Hours {
-    fn from_i32(input: i32) -> Self {
-        Self(((input as u32) % 24) as u8)
-    }
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::fmt::{self, Formatter, Display};
+use crate::duration::NANOS_PER_MINUTE;
+
+#[cfg(feature = "chrono")]
+mod chrono_conv;
+#[cfg(feature = "std")]
+pub mod cli;
+mod day;
+mod duration;
+pub mod encoding;
+#[cfg(feature = "std")]
+pub mod face;
+#[cfg(feature = "std")]
+mod format;
+mod modular;
+mod parse;
+mod precise;
+mod range;
+#[cfg(feature = "std")]
+mod schedule;
+#[cfg(feature = "std")]
+pub mod spoken;
+mod stopwatch;
+mod zoned;
+
+pub use crate::day::DayClock;
+pub use crate::duration::{ClockDuration, DurationError};
+#[cfg(feature = "std")]
+pub use crate::format::{ClockFormat, FormatError, Formatted};
+pub use crate::modular::{Dial, DialMismatch, ModularClock};
+pub use crate::parse::{ParseClockError, ParseErrorKind};
+pub use crate::precise::PreciseClock;
+pub use crate::range::{ClockRange, ClockRangeIter, ClockRanges, ClockSteps};
+#[cfg(feature = "std")]
+pub use crate::schedule::{Alarm, Occurrences, Schedule, Weekday, Weekdays};
+#[cfg(feature = "std")]
+pub use crate::stopwatch::SystemTimeSource;
+pub use crate::stopwatch::{Countdown, Lap, ManualTimeSource, Stopwatch, TimeSource};
+pub use crate::zoned::{UtcOffset, ZonedClock};
+
+const HOURS_PER_DAY: i64 = 24;
+const MINUTES_PER_HOUR: i64 = 60;
+const MINUTES_PER_DAY: i64 = HOURS_PER_DAY * MINUTES_PER_HOUR;
+
+/// Reduces a count of minutes below two days to the minutes of one day, without branching,
+/// so that loops over many clocks vectorize.
+const fn wrap_day(minutes: u32) -> u16 {
+    let over = (minutes >= MINUTES_PER_DAY as u32) as u32;
+    (minutes - over * MINUTES_PER_DAY as u32) as u16
 }
 
-impl Add<i32> for Hours {
-    type Output = Self;
-    fn add(self, other: i32) -> Self {
-        Self::from_i32(self.0 as i32 + other % 24)
+/// Hour on the 12-hour dial, from 1 to 12.
+pub(crate) fn hour12(hour: u8) -> u32 {
+    match hour % 12 {
+        0 => 12,
+        hour => hour.into(),
     }
 }
 
-impl Display for Hours {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        self.0.fmt(f)
+/// Time of day with the minute precision, wrapping around at midnight.
+///
+/// Clocks are ordered by the minutes since midnight, so 00:00 is the smallest one;
+/// use [is_between](#method.is_between) or [cyclic_cmp](#method.cyclic_cmp) for the comparisons that wrap around.
+///
+/// A clock is stored as a single `u16` holding the minutes since midnight, so a slice of clocks
+/// has the layout of a slice of `u16`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Clock(u16);
+
+impl Clock {
+    pub const MIDNIGHT: Clock = Clock::from_hm(0, 0);
+    pub const NOON: Clock = Clock::from_hm(12, 0);
+
+    /// The clock at `hours` and `minutes`, wrapping around, e.g. `Clock::new(25, -30)` is 00:30.
+    pub const fn new(hours: i32, minutes: i32) -> Self {
+        Self::from_minutes_of_day(hours as i64 * MINUTES_PER_HOUR + minutes as i64)
+    }
+
+    /// The clock at the already normalized `hours` and `minutes`, usable in constants;
+    /// see also the [clock!](macro.clock.html) macro.
+    ///
+    /// # Panics
+    /// This function will panic, or fail to compile in a constant, if the hours aren't below 24
+    /// or the minutes aren't below 60.
+    pub const fn from_hm(hours: u8, minutes: u8) -> Self {
+        match Self::checked_from_hm(hours, minutes) {
+            Some(clock) => clock,
+            None => panic!("Clock out of range"),
+        }
+    }
+
+    /// The clock at `hours` and `minutes`, or None if the hours aren't below 24 or the minutes aren't below 60.
+    pub const fn checked_from_hm(hours: u8, minutes: u8) -> Option<Self> {
+        if (hours as i64) < HOURS_PER_DAY && (minutes as i64) < MINUTES_PER_HOUR {
+            Some(Self(hours as u16 * MINUTES_PER_HOUR as u16 + minutes as u16))
+        } else {
+            None
+        }
+    }
+
+    pub fn add_minutes(&self, minutes: i32) -> Self {
+        self.add_minutes_i64(minutes.into())
+    }
+
+    fn add_minutes_i64(&self, minutes: i64) -> Self {
+        // Whole days don't affect the result, and dropping them keeps the sum below two days.
+        Self(wrap_day(u32::from(self.0) + minutes.rem_euclid(MINUTES_PER_DAY) as u32))
+    }
+
+    /// Adds the minutes, also returning the number of days crossed, negative if going backwards past midnight.
+    pub fn overflowing_add_minutes(&self, minutes: i64) -> (Self, i64) {
+        let total = self.minutes_of_day() + minutes.rem_euclid(MINUTES_PER_DAY);
+        let days = minutes.div_euclid(MINUTES_PER_DAY) + total.div_euclid(MINUTES_PER_DAY);
+        (self.add_minutes_i64(minutes), days)
+    }
+
+    /// Subtracts the minutes, also returning the number of days crossed, negative if going backwards past midnight.
+    pub fn overflowing_sub_minutes(&self, minutes: i64) -> (Self, i64) {
+        let total = self.minutes_of_day() - minutes.rem_euclid(MINUTES_PER_DAY);
+        let days = -minutes.div_euclid(MINUTES_PER_DAY) + total.div_euclid(MINUTES_PER_DAY);
+        (self.add_minutes_i64(-minutes.rem_euclid(MINUTES_PER_DAY)), days)
+    }
+
+    /// Adds the duration rounding down to the whole minute, like `+`, also returning the number of days crossed.
+    pub fn overflowing_add(&self, duration: ClockDuration) -> (Self, i64) {
+        self.overflowing_add_minutes(duration.as_nanos().div_euclid(NANOS_PER_MINUTE))
+    }
+
+    pub const fn hour(&self) -> u8 {
+        (self.0 / MINUTES_PER_HOUR as u16) as u8
+    }
+
+    pub const fn minute(&self) -> u8 {
+        (self.0 % MINUTES_PER_HOUR as u16) as u8
+    }
+
+    /// Minutes since midnight, from 0 to 1439.
+    pub const fn minutes_since_midnight(&self) -> u16 {
+        self.0
+    }
+
+    /// The clock the given number of minutes after midnight, wrapping around like [new](#method.new).
+    pub const fn from_minutes_since_midnight(minutes: i64) -> Self {
+        Self::from_minutes_of_day(minutes)
+    }
+
+    const fn from_minutes_of_day(minutes: i64) -> Self {
+        Self(minutes.rem_euclid(MINUTES_PER_DAY) as u16)
+    }
+
+    fn minutes_of_day(&self) -> i64 {
+        self.minutes_since_midnight().into()
+    }
+
+    /// Checks whether the clock lies on the way from `start` to `end`, going forward and wrapping past midnight
+    /// if needed. `start` is included and `end` is not, so 23:00 and 01:00 are between 22:00 and 02:00,
+    /// but 02:00 is not; nothing is between two equal clocks.
+    pub fn is_between(&self, start: Clock, end: Clock) -> bool {
+        ClockRange::new(start, end).contains(self)
+    }
+
+    /// Compares the clocks by how long it takes to reach them going forward from `origin`,
+    /// e.g. 23:00 is before 01:00 counting from 22:00.
+    pub fn cyclic_cmp(&self, other: &Clock, origin: Clock) -> Ordering {
+        (*self - origin).cmp(&(*other - origin))
+    }
+
+    /// Signed distance from this clock to the other one, going whichever way round the dial is shorter.
+    ///
+    /// The result lies in the range from -11:59 to 12:00; exactly opposite clocks are 12 hours forward.
+    pub fn shortest_distance(&self, other: &Clock) -> ClockDuration {
+        let forward = (other.minutes_of_day() - self.minutes_of_day()).rem_euclid(MINUTES_PER_DAY);
+        if forward > MINUTES_PER_DAY / 2 {
+            ClockDuration::from_minutes(forward - MINUTES_PER_DAY)
+        } else {
+            ClockDuration::from_minutes(forward)
+        }
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct Minutes(u8);
+/// Forward distance from `other` to `self`, i.e. how long it takes to get from `other` to `self`.
+///
+/// The result lies in the range from 00:00 to 23:59.
+impl Sub for Clock {
+    type Output = ClockDuration;
+    fn sub(self, other: Clock) -> ClockDuration {
+        ClockDuration::from_minutes(wrap_day(u32::from(self.0) + MINUTES_PER_DAY as u32 - u32::from(other.0)).into())
+    }
+}
 
-impl Minutes {
-    fn from_i32(input: i32) -> (Self, i32) {
-        (Self(((input as u32) % 60) as u8), input / 60)
+impl Sub<&Clock> for &Clock {
+    type Output = ClockDuration;
+    fn sub(self, other: &Clock) -> ClockDuration {
+        *self - *other
     }
 }
 
-impl Add<i32> for Minutes {
-    type Output = (Self, i32);
-    fn add(self, _other: i32) -> (Self, i32) {
-        unimplemented!();
+/// Adds the duration, rounding down to the whole minute; see [PreciseClock](struct.PreciseClock.html) for the exact arithmetic.
+impl Add<ClockDuration> for Clock {
+    type Output = Self;
+    fn add(self, duration: ClockDuration) -> Self {
+        PreciseClock::from(self).add_duration(duration).to_clock()
     }
 }
 
-impl Display for Minutes {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        self.0.fmt(f)
+/// Subtracts the duration, rounding down to the whole minute; see [PreciseClock](struct.PreciseClock.html) for the exact arithmetic.
+impl Sub<ClockDuration> for Clock {
+    type Output = Self;
+    fn sub(self, duration: ClockDuration) -> Self {
+        (PreciseClock::from(self) - duration).to_clock()
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct Clock(Hours, Minutes);
+macro_rules! minutes_ops {
+    ($($int:ty),+) => {
+        $(
+            impl Add<$int> for Clock {
+                type Output = Self;
+                fn add(self, minutes: $int) -> Self {
+                    self.add_minutes_i64(minutes.into())
+                }
+            }
+            impl Sub<$int> for Clock {
+                type Output = Self;
+                fn sub(self, minutes: $int) -> Self {
+                    // Negating i64::MIN would overflow, so the whole days are dropped first.
+                    self.add_minutes_i64(-i64::from(minutes).rem_euclid(MINUTES_PER_DAY))
+                }
+            }
+            impl AddAssign<$int> for Clock {
+                fn add_assign(&mut self, minutes: $int) {
+                    *self = self.add_minutes_i64(minutes.into());
+                }
+            }
+            impl SubAssign<$int> for Clock {
+                fn sub_assign(&mut self, minutes: $int) {
+                    *self = self.add_minutes_i64(-i64::from(minutes).rem_euclid(MINUTES_PER_DAY));
+                }
+            }
+        )+
+    };
+}
+
+minutes_ops!(i32, i64);
 
-impl Clock {
-    pub fn new(hours: i32, minutes: i32) -> Self {
-        let (minutes, wrap) = Minutes::from_i32(minutes);
-        let hours = Hours::from_i32(hours) + wrap;
-        Self(hours, minutes)
+/// Clock constant from an `HH:MM` literal, checked at compile time, e.g. `clock!(08:30)`.
+///
+/// Literals out of range, such as `25:00`, don't compile unless wrapping around is requested with
+/// `clock!(25:00, wrapping)`, which gives 01:00 like [Clock::new](struct.Clock.html#method.new).
+///
+/// ```
+/// # use clock::{clock, Clock};
+/// const OPENING: Clock = clock!(08:30);
+/// assert_eq!(OPENING, Clock::new(8, 30));
+/// assert_eq!(clock!(24:90, wrapping), clock!(01:30));
+/// ```
+///
+/// ```compile_fail
+/// # use clock::clock;
+/// let late = clock!(25:00);
+/// ```
+#[macro_export]
+macro_rules! clock {
+    ($hours:literal : $minutes:literal) => {{
+        const CLOCK: $crate::Clock = $crate::__clock_literal(stringify!($hours), stringify!($minutes), false);
+        CLOCK
+    }};
+    ($hours:literal : $minutes:literal, wrapping) => {{
+        const CLOCK: $crate::Clock = $crate::__clock_literal(stringify!($hours), stringify!($minutes), true);
+        CLOCK
+    }};
+}
+
+/// Builds the clock for [clock!](macro.clock.html) from the source text of its literals,
+/// which keeps zero-padded numbers such as `08` from being linted as octal look-alikes.
+#[doc(hidden)]
+pub const fn __clock_literal(hours: &str, minutes: &str, wrapping: bool) -> Clock {
+    let (hours, minutes) = (literal_value(hours), literal_value(minutes));
+    if wrapping {
+        Clock::new(hours, minutes)
+    } else if hours >= 0 && hours < HOURS_PER_DAY as i32 && minutes >= 0 && minutes < MINUTES_PER_HOUR as i32 {
+        Clock::from_hm(hours as u8, minutes as u8)
+    } else {
+        panic!("Clock literal out of range; use clock!(HH:MM, wrapping) to wrap around")
     }
+}
 
-    pub fn add_minutes(&self, minutes: i32) -> Self {
-        let (minutes, wrap) = self.1 + minutes;
-        let hours = self.0 + wrap;
-        Self(hours, minutes)
+/// Value of a decimal literal with an optional minus sign, e.g. `08` or `-15`.
+const fn literal_value(literal: &str) -> i32 {
+    let bytes = literal.as_bytes();
+    let (mut value, mut negative, mut i) = (0i32, false, 0);
+    while i < bytes.len() {
+        match bytes[i] {
+            b'-' if value == 0 && !negative => negative = true,
+            b' ' | b'_' => {}
+            digit @ b'0'..=b'9' => match value.checked_mul(10) {
+                Some(tens) => value = tens + (digit - b'0') as i32,
+                None => panic!("Clock literal out of range"),
+            },
+            _ => panic!("Clock literal must be a decimal integer"),
+        }
+        i += 1;
+    }
+    if negative {
+        -value
+    } else {
+        value
     }
 }
 
+/// Formats the clock as `HH:MM`, or on the 12-hour dial as `H:MM AM` with the alternate flag, `{:#}`.
 impl Display for Clock {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{:02}:{:02}", self.0, self.1)?;
+        if f.alternate() {
+            let meridiem = if self.hour() < 12 { "AM" } else { "PM" };
+            return write!(f, "{}:{:02} {}", hour12(self.hour()), self.minute(), meridiem);
+        }
+        write!(f, "{:02}:{:02}", self.hour(), self.minute())?;
         Ok(())
     }
 }
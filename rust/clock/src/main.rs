@@ -0,0 +1,25 @@
+use clock::cli::{self, CliError};
+use std::env;
+use std::process;
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", cli::USAGE);
+        return;
+    }
+    let json = args.iter().any(|arg| arg == "--json");
+    args.retain(|arg| arg != "--json");
+    match cli::run(&args) {
+        Ok(output) if json => println!("{}", output.to_json()),
+        Ok(output) => println!("{}", output.to_text()),
+        Err(err @ CliError::Usage(_)) => {
+            eprintln!("{}\n\n{}", err, cli::USAGE);
+            process::exit(2);
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use crate::duration::{NANOS_PER_MINUTE, NANOS_PER_SECOND};
+use crate::{Clock, ClockDuration, HOURS_PER_DAY, MINUTES_PER_DAY, MINUTES_PER_HOUR};
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// Layout of a day: how many hours it has, how many minutes are in an hour and, optionally,
+/// how many seconds are in a minute, together with the real length of the day.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Dial {
+    hours_per_day: u32,
+    minutes_per_hour: u32,
+    seconds_per_minute: Option<u32>,
+    day_length: ClockDuration,
+}
+
+const EARTH_DAY: ClockDuration = ClockDuration::from_nanos(MINUTES_PER_DAY * NANOS_PER_MINUTE);
+
+impl Dial {
+    /// The usual 24 hours of 60 minutes, without seconds; the dial of [Clock](struct.Clock.html).
+    pub const STANDARD: Dial = Dial::new(HOURS_PER_DAY as u32, MINUTES_PER_HOUR as u32);
+
+    /// The decimal time of the French revolution: 10 hours of 100 minutes of 100 seconds.
+    pub const DECIMAL: Dial = Dial::new(10, 100).with_seconds(100);
+
+    /// Coordinated Mars Time: a sol of 24 hours of 60 minutes of 60 seconds,
+    /// each about 2.7% longer than on Earth since a sol lasts 88,775.244 seconds.
+    pub const MARS: Dial = Dial::new(24, 60)
+        .with_seconds(60)
+        .with_day_length(ClockDuration::from_nanos(88_775_244 * NANOS_PER_SECOND / 1000));
+
+    /// A day of `hours_per_day` hours of `minutes_per_hour` minutes, lasting 24 real hours.
+    ///
+    /// # Panics
+    /// This function will panic if either count is zero, or if the day has more than `u32::MAX` minutes.
+    pub const fn new(hours_per_day: u32, minutes_per_hour: u32) -> Self {
+        assert!(hours_per_day > 0 && minutes_per_hour > 0, "Dial units must be positive");
+        assert!(hours_per_day.checked_mul(minutes_per_hour).is_some(), "Dial has too many minutes");
+        Dial {
+            hours_per_day,
+            minutes_per_hour,
+            seconds_per_minute: None,
+            day_length: EARTH_DAY,
+        }
+    }
+
+    /// The same dial with `seconds_per_minute` seconds in a minute.
+    ///
+    /// # Panics
+    /// This function will panic if `seconds_per_minute` is zero, or if the day has more than `u32::MAX` seconds.
+    pub const fn with_seconds(self, seconds_per_minute: u32) -> Self {
+        assert!(seconds_per_minute > 0, "Dial units must be positive");
+        let minutes_per_day = self.hours_per_day * self.minutes_per_hour;
+        assert!(minutes_per_day.checked_mul(seconds_per_minute).is_some(), "Dial has too many seconds");
+        Dial {
+            seconds_per_minute: Some(seconds_per_minute),
+            ..self
+        }
+    }
+
+    /// The same dial with a day lasting `day_length` of real time, used to convert the real durations.
+    ///
+    /// # Panics
+    /// This function will panic if `day_length` isn't positive.
+    pub const fn with_day_length(self, day_length: ClockDuration) -> Self {
+        assert!(day_length.as_nanos() > 0, "Day length must be positive");
+        Dial { day_length, ..self }
+    }
+
+    pub fn hours_per_day(&self) -> u32 {
+        self.hours_per_day
+    }
+
+    pub fn minutes_per_hour(&self) -> u32 {
+        self.minutes_per_hour
+    }
+
+    pub fn seconds_per_minute(&self) -> Option<u32> {
+        self.seconds_per_minute
+    }
+
+    pub fn day_length(&self) -> ClockDuration {
+        self.day_length
+    }
+
+    /// Number of the smallest units, seconds or minutes, in a tick of the clock.
+    fn ticks_per_minute(&self) -> u64 {
+        self.seconds_per_minute.unwrap_or(1).into()
+    }
+
+    /// Checked to fit in a `u32` when the dial is built, so this can't overflow.
+    fn ticks_per_day(&self) -> u64 {
+        u64::from(self.hours_per_day) * u64::from(self.minutes_per_hour) * self.ticks_per_minute()
+    }
+}
+
+/// Clock on an arbitrary [Dial](struct.Dial.html), wrapping around at the end of its day.
+///
+/// [Clock](struct.Clock.html) is the modular clock on [Dial::STANDARD](struct.Dial.html#associatedconstant.STANDARD):
+/// it converts into one losslessly, and back with `TryFrom`, which fails on any other dial.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModularClock {
+    dial: Dial,
+    /// Seconds since midnight, or minutes if the dial has no seconds.
+    ticks: u64,
+}
+
+impl ModularClock {
+    /// The clock at `hours` and `minutes`, wrapping around like [Clock::new](struct.Clock.html#method.new).
+    pub fn new(dial: Dial, hours: i64, minutes: i64) -> Self {
+        ModularClock { dial, ticks: 0 }.add_hours(hours).add_minutes(minutes)
+    }
+
+    /// The clock at `hours`, `minutes` and `seconds`, wrapping around like [Clock::new](struct.Clock.html#method.new).
+    ///
+    /// # Panics
+    /// This function will panic if the dial has no seconds.
+    pub fn with_seconds(dial: Dial, hours: i64, minutes: i64, seconds: i64) -> Self {
+        Self::new(dial, hours, minutes).add_seconds(seconds)
+    }
+
+    pub fn dial(&self) -> Dial {
+        self.dial
+    }
+
+    pub fn hour(&self) -> u32 {
+        (self.ticks / self.ticks_per_hour()) as u32
+    }
+
+    pub fn minute(&self) -> u32 {
+        (self.ticks / self.dial.ticks_per_minute() % u64::from(self.dial.minutes_per_hour)) as u32
+    }
+
+    /// The second of the minute, or None if the dial has no seconds.
+    pub fn second(&self) -> Option<u32> {
+        let seconds = self.dial.seconds_per_minute?;
+        Some((self.ticks % u64::from(seconds)) as u32)
+    }
+
+    pub fn add_hours(&self, hours: i64) -> Self {
+        self.add_ticks(hours, self.ticks_per_hour())
+    }
+
+    pub fn add_minutes(&self, minutes: i64) -> Self {
+        self.add_ticks(minutes, self.dial.ticks_per_minute())
+    }
+
+    /// # Panics
+    /// This function will panic if the dial has no seconds.
+    pub fn add_seconds(&self, seconds: i64) -> Self {
+        assert!(self.dial.seconds_per_minute.is_some(), "Dial has no seconds");
+        self.add_ticks(seconds, 1)
+    }
+
+    /// Adds the real duration, measured against the length of the dial's day and rounded down to a whole tick.
+    pub fn add_duration(&self, duration: ClockDuration) -> Self {
+        let day = i128::from(self.dial.day_length.as_nanos());
+        let nanos = i128::from(duration.as_nanos()).rem_euclid(day);
+        let ticks = nanos * i128::from(self.dial.ticks_per_day()) / day;
+        self.add_ticks(ticks as i64, 1)
+    }
+
+    /// Real time elapsed since midnight, rounded down to the nanosecond.
+    pub fn since_midnight(&self) -> ClockDuration {
+        let nanos = u128::from(self.ticks) * self.dial.day_length.as_nanos() as u128 / u128::from(self.dial.ticks_per_day());
+        ClockDuration::from_nanos(nanos as i64)
+    }
+
+    /// The clock on another dial at the same fraction of the day, rounded down to a whole tick,
+    /// e.g. 12:00 on the standard dial is 5:00:00 on the decimal one.
+    pub fn to_dial(&self, dial: Dial) -> ModularClock {
+        let ticks = u128::from(self.ticks) * u128::from(dial.ticks_per_day()) / u128::from(self.dial.ticks_per_day());
+        ModularClock {
+            dial,
+            ticks: ticks as u64,
+        }
+    }
+
+    /// The [Clock](struct.Clock.html) at the same fraction of the day, rounded down to the minute.
+    pub fn to_clock(&self) -> Clock {
+        Clock::from_minutes_since_midnight(self.to_dial(Dial::STANDARD).ticks as i64)
+    }
+
+    fn ticks_per_hour(&self) -> u64 {
+        u64::from(self.dial.minutes_per_hour) * self.dial.ticks_per_minute()
+    }
+
+    fn add_ticks(&self, count: i64, scale: u64) -> Self {
+        // Working modulo the day keeps the product from overflowing.
+        let day = i128::from(self.dial.ticks_per_day());
+        let ticks = i128::from(self.ticks) + i128::from(count) * i128::from(scale) % day;
+        ModularClock {
+            dial: self.dial,
+            ticks: ticks.rem_euclid(day) as u64,
+        }
+    }
+}
+
+impl Clock {
+    pub const DIAL: Dial = Dial::STANDARD;
+}
+
+/// Error returned when converting a [ModularClock](struct.ModularClock.html) which isn't on
+/// [Dial::STANDARD](struct.Dial.html#associatedconstant.STANDARD) into a [Clock](struct.Clock.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialMismatch {
+    /// The dial of the modular clock.
+    pub dial: Dial,
+}
+
+impl Display for DialMismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "clock isn't on the standard dial")
+    }
+}
+
+impl Error for DialMismatch {}
+
+/// The same clock, only if it's on the standard dial; use [to_clock](struct.ModularClock.html#method.to_clock)
+/// to convert from the other dials.
+impl TryFrom<ModularClock> for Clock {
+    type Error = DialMismatch;
+    fn try_from(modular: ModularClock) -> Result<Self, DialMismatch> {
+        if modular.dial == Clock::DIAL {
+            Ok(Clock::from_minutes_since_midnight(modular.ticks as i64))
+        } else {
+            Err(DialMismatch { dial: modular.dial })
+        }
+    }
+}
+
+impl From<Clock> for ModularClock {
+    fn from(clock: Clock) -> Self {
+        ModularClock {
+            dial: Dial::STANDARD,
+            ticks: clock.minutes_since_midnight().into(),
+        }
+    }
+}
+
+/// Number of digits needed for the values below `count`.
+fn width(count: u32) -> usize {
+    let mut largest = count.saturating_sub(1) / 10;
+    let mut digits = 1;
+    while largest > 0 {
+        largest /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// Formats the clock as `HH:MM` or `HH:MM:SS`, padding each part to the width of its largest value,
+/// e.g. `08:30` on the standard dial and `5:00:00` on the decimal one.
+impl Display for ModularClock {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let dial = &self.dial;
+        write!(
+            f,
+            "{:0hours$}:{:0minutes$}",
+            self.hour(),
+            self.minute(),
+            hours = width(dial.hours_per_day),
+            minutes = width(dial.minutes_per_hour)
+        )?;
+        if let (Some(second), Some(seconds)) = (self.second(), dial.seconds_per_minute) {
+            write!(f, ":{:0width$}", second, width = width(seconds))?;
+        }
+        Ok(())
+    }
+}
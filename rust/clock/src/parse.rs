@@ -0,0 +1,363 @@
+use crate::{Clock, PreciseClock};
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+/// Reason of a [ParseClockError](struct.ParseClockError.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input has nothing but whitespace.
+    Empty,
+    /// A character which can't appear at this position.
+    UnexpectedChar(char),
+    /// The input ends where more was expected.
+    UnexpectedEnd,
+    /// The hour is above 23, or not in `1..=12` together with AM/PM.
+    HourOutOfRange,
+    MinuteOutOfRange,
+    SecondOutOfRange,
+    /// The UTC offset is not within ±23:59.
+    OffsetOutOfRange,
+    /// The time has no UTC offset, which the target type requires.
+    MissingOffset,
+    /// The time has seconds or their fraction, which the target type can't hold.
+    TooPrecise,
+}
+
+/// Error returned when a string can't be parsed as a clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseClockError {
+    position: usize,
+    kind: ParseErrorKind,
+}
+
+impl ParseClockError {
+    pub(crate) fn new(position: usize, kind: ParseErrorKind) -> Self {
+        ParseClockError { position, kind }
+    }
+
+    /// Byte offset of the offending part of the input.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+impl Display for ParseClockError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => write!(f, "empty input"),
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {:?} at position {}", c, self.position),
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of input at position {}", self.position),
+            ParseErrorKind::HourOutOfRange => write!(f, "hour out of range at position {}", self.position),
+            ParseErrorKind::MinuteOutOfRange => write!(f, "minute out of range at position {}", self.position),
+            ParseErrorKind::SecondOutOfRange => write!(f, "second out of range at position {}", self.position),
+            ParseErrorKind::OffsetOutOfRange => write!(f, "UTC offset out of range at position {}", self.position),
+            ParseErrorKind::MissingOffset => write!(f, "missing UTC offset at position {}", self.position),
+            ParseErrorKind::TooPrecise => write!(f, "unsupported seconds at position {}", self.position),
+        }
+    }
+}
+
+impl Error for ParseClockError {}
+
+/// Components of a parsed time of day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Parsed {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanos: u32,
+    /// Position of the seconds in the input, if they were present.
+    pub seconds_at: Option<usize>,
+    /// UTC offset in minutes, if one was present; `Z` is zero.
+    pub offset: Option<i32>,
+    /// Position right after the time and its offset, if any.
+    pub end: usize,
+}
+
+impl Parsed {
+    /// The clock, if the time has no seconds or they are zero.
+    pub fn to_clock(self) -> Result<Clock, ParseClockError> {
+        match self.seconds_at {
+            Some(at) if self.second != 0 || self.nanos != 0 => Err(ParseClockError::new(at, ParseErrorKind::TooPrecise)),
+            _ => Ok(Clock::new(self.hour.into(), self.minute.into())),
+        }
+    }
+}
+
+struct Cursor<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseClockError {
+        ParseClockError::new(self.position, kind)
+    }
+
+    /// Error for whatever stands at the current position.
+    fn unexpected(&self) -> ParseClockError {
+        match self.peek() {
+            Some(c) => self.error(ParseErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    /// Consumes the run of ASCII digits, returning it with its starting position.
+    fn digits(&mut self) -> (usize, &'a str) {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+        (start, &self.input[start..self.position])
+    }
+
+    /// Consumes exactly `count` digits.
+    fn fixed_digits(&mut self, count: usize) -> Result<(usize, u32), ParseClockError> {
+        let start = self.position;
+        for _ in 0..count {
+            match self.peek() {
+                Some(c) if c.is_ascii_digit() => {
+                    self.bump();
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+        Ok((start, self.input[start..self.position].parse().unwrap_or(0)))
+    }
+
+    /// Case-insensitively consumes one of the meridiem markers, returning whether it's PM.
+    fn meridiem(&mut self) -> Option<bool> {
+        let rest = &self.input.as_bytes()[self.position..];
+        for &(marker, pm) in &[("a.m.", false), ("p.m.", true), ("am", false), ("pm", true)] {
+            if rest.len() >= marker.len() && rest[..marker.len()].eq_ignore_ascii_case(marker.as_bytes()) {
+                self.position += marker.len();
+                return Some(pm);
+            }
+        }
+        None
+    }
+}
+
+fn check(value: u32, max: u32, position: usize, kind: ParseErrorKind) -> Result<u8, ParseClockError> {
+    if value > max {
+        Err(ParseClockError::new(position, kind))
+    } else {
+        Ok(value as u8)
+    }
+}
+
+/// Parses the fraction of a second after its separator, with up to nine digits.
+fn fraction(cursor: &mut Cursor) -> Result<u32, ParseClockError> {
+    let (start, digits) = cursor.digits();
+    if digits.is_empty() {
+        return Err(cursor.unexpected());
+    }
+    if digits.len() > 9 {
+        return Err(ParseClockError::new(start + 9, ParseErrorKind::TooPrecise));
+    }
+    let value: u32 = digits.parse().unwrap_or(0);
+    Ok(value * 10u32.pow(9 - digits.len() as u32))
+}
+
+/// Parses `Z`, `±HH`, `±HHMM` or `±HH:MM`.
+fn offset(cursor: &mut Cursor) -> Result<Option<i32>, ParseClockError> {
+    let sign = match cursor.peek() {
+        Some('Z') | Some('z') => {
+            cursor.bump();
+            return Ok(Some(0));
+        }
+        Some('+') => 1,
+        Some('-') | Some('\u{2212}') => -1,
+        _ => return Ok(None),
+    };
+    let start = cursor.position;
+    cursor.bump();
+    let (_, hours) = cursor.fixed_digits(2)?;
+    let minutes = if cursor.eat(':') || cursor.peek().is_some_and(|c| c.is_ascii_digit()) {
+        let (at, minutes) = cursor.fixed_digits(2)?;
+        check(minutes, 59, at, ParseErrorKind::OffsetOutOfRange)?
+    } else {
+        0
+    };
+    let hours = check(hours, 23, start, ParseErrorKind::OffsetOutOfRange)?;
+    Ok(Some(sign * (i32::from(hours) * 60 + i32::from(minutes))))
+}
+
+/// Parses the supported time formats: `HH:MM`, `HH:MM:SS[.fff]`, `H:MM am/pm`, military `HHMM`,
+/// and ISO 8601 times with an optional `T` prefix and `Z` or `±HH:MM` suffix.
+pub(crate) fn parse(input: &str) -> Result<Parsed, ParseClockError> {
+    let mut cursor = Cursor { input, position: 0 };
+    cursor.skip_whitespace();
+    if cursor.peek().is_none() {
+        return Err(cursor.error(ParseErrorKind::Empty));
+    }
+    cursor.eat('T');
+    let (hour_at, hour_digits) = cursor.digits();
+    let mut parsed = Parsed {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanos: 0,
+        seconds_at: None,
+        offset: None,
+        end: 0,
+    };
+    let (hour, minute_at, minute);
+    if cursor.eat(':') {
+        // Extended format, HH:MM[:SS[.fff]].
+        match hour_digits.len() {
+            0 => return Err(ParseClockError::new(hour_at, ParseErrorKind::UnexpectedChar(':'))),
+            1 | 2 => {}
+            _ => return Err(ParseClockError::new(hour_at, ParseErrorKind::HourOutOfRange)),
+        }
+        hour = hour_digits.parse().unwrap_or(0);
+        let (at, value) = cursor.fixed_digits(2)?;
+        minute_at = at;
+        minute = value;
+        if cursor.eat(':') {
+            let (at, second) = cursor.fixed_digits(2)?;
+            parsed.second = check(second, 59, at, ParseErrorKind::SecondOutOfRange)?;
+            parsed.seconds_at = Some(at);
+            if cursor.eat('.') || cursor.eat(',') {
+                parsed.nanos = fraction(&mut cursor)?;
+            }
+        }
+    } else {
+        // Basic format, HHMM or HHMMSS[.fff].
+        let digit = |i: usize| -> u32 { hour_digits[i..i + 2].parse().unwrap_or(0) };
+        match hour_digits.len() {
+            4 | 6 => {
+                hour = digit(0);
+                minute_at = hour_at + 2;
+                minute = digit(2);
+                if hour_digits.len() == 6 {
+                    parsed.second = check(digit(4), 59, hour_at + 4, ParseErrorKind::SecondOutOfRange)?;
+                    parsed.seconds_at = Some(hour_at + 4);
+                    if cursor.eat('.') || cursor.eat(',') {
+                        parsed.nanos = fraction(&mut cursor)?;
+                    }
+                }
+            }
+            0..=3 => return Err(cursor.unexpected()),
+            len => {
+                // The first digit past the longest basic format is the offending one.
+                let at = hour_at + if len == 5 { 4 } else { 6 };
+                let c = char::from(input.as_bytes()[at]);
+                return Err(ParseClockError::new(at, ParseErrorKind::UnexpectedChar(c)));
+            }
+        }
+    }
+    parsed.minute = check(minute, 59, minute_at, ParseErrorKind::MinuteOutOfRange)?;
+    let after_time = cursor.position;
+    cursor.skip_whitespace();
+    match cursor.meridiem() {
+        Some(pm) => {
+            if !(1..=12).contains(&hour) {
+                return Err(ParseClockError::new(hour_at, ParseErrorKind::HourOutOfRange));
+            }
+            parsed.hour = (hour % 12) as u8 + if pm { 12 } else { 0 };
+        }
+        None => {
+            // 24:00 is the ISO 8601 notation for the midnight at the end of the day.
+            let end_of_day = hour == 24 && parsed.minute == 0 && parsed.second == 0 && parsed.nanos == 0;
+            parsed.hour = if end_of_day { 0 } else { check(hour, 23, hour_at, ParseErrorKind::HourOutOfRange)? };
+            if cursor.position == after_time {
+                parsed.offset = offset(&mut cursor)?;
+            }
+        }
+    }
+    parsed.end = cursor.position;
+    finish(cursor, parsed)
+}
+
+/// Parses a standalone UTC offset, `Z` or `±HH[[:]MM]`, into minutes.
+pub(crate) fn parse_offset(input: &str) -> Result<i32, ParseClockError> {
+    let mut cursor = Cursor { input, position: 0 };
+    cursor.skip_whitespace();
+    if cursor.peek().is_none() {
+        return Err(cursor.error(ParseErrorKind::Empty));
+    }
+    match offset(&mut cursor)? {
+        Some(minutes) => finish(cursor, minutes),
+        None => Err(cursor.unexpected()),
+    }
+}
+
+/// Rejects anything but whitespace after the parsed value.
+fn finish<T>(mut cursor: Cursor, value: T) -> Result<T, ParseClockError> {
+    cursor.skip_whitespace();
+    match cursor.peek() {
+        None => Ok(value),
+        Some(_) => Err(cursor.unexpected()),
+    }
+}
+
+impl FromStr for Clock {
+    type Err = ParseClockError;
+    /// Parses the clock, see [PreciseClock](struct.PreciseClock.html#impl-FromStr) for the formats.
+    ///
+    /// Non-zero seconds are rejected rather than truncated, and the UTC offset, if any, is ignored.
+    fn from_str(input: &str) -> Result<Self, ParseClockError> {
+        parse(input)?.to_clock()
+    }
+}
+
+impl FromStr for PreciseClock {
+    type Err = ParseClockError;
+    /// Parses `HH:MM`, `HH:MM:SS[.fff]`, `H:MM am/pm`, military `HHMM`, and ISO 8601 times,
+    /// such as `T083015.250Z` or `08:30:15+05:30`. The UTC offset, if any, is ignored.
+    fn from_str(input: &str) -> Result<Self, ParseClockError> {
+        let parsed = parse(input)?;
+        Ok(PreciseClock::with_nanos(
+            parsed.hour.into(),
+            parsed.minute.into(),
+            parsed.second.into(),
+            parsed.nanos.into(),
+        ))
+    }
+}
+
+impl TryFrom<&str> for Clock {
+    type Error = ParseClockError;
+    fn try_from(input: &str) -> Result<Self, ParseClockError> {
+        input.parse()
+    }
+}
+
+impl TryFrom<&str> for PreciseClock {
+    type Error = ParseClockError;
+    fn try_from(input: &str) -> Result<Self, ParseClockError> {
+        input.parse()
+    }
+}
@@ -0,0 +1,153 @@
+use crate::duration::{NANOS_PER_MINUTE, NANOS_PER_SECOND};
+use crate::{Clock, ClockDuration, MINUTES_PER_DAY};
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+const NANOS_PER_DAY: i64 = MINUTES_PER_DAY * NANOS_PER_MINUTE;
+
+/// Clock with the second and nanosecond precision.
+///
+/// It follows the same wrap-around rules and ordering as [Clock](struct.Clock.html), carrying the overflow
+/// from nanoseconds up to hours.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PreciseClock {
+    clock: Clock,
+    /// Nanoseconds since the start of the minute.
+    nanos: u64,
+}
+
+impl PreciseClock {
+    pub fn new(hours: i32, minutes: i32, seconds: i32) -> Self {
+        Self::with_nanos(hours, minutes, seconds, 0)
+    }
+
+    pub fn with_nanos(hours: i32, minutes: i32, seconds: i32, nanos: i64) -> Self {
+        let clock = Clock::new(hours, minutes);
+        Self::from(&clock)
+            .add_duration(ClockDuration::from_seconds(seconds.into()))
+            .add_duration(ClockDuration::from_nanos(nanos))
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.clock.hour()
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.clock.minute()
+    }
+
+    pub fn second(&self) -> u8 {
+        (self.nanos / NANOS_PER_SECOND as u64) as u8
+    }
+
+    /// Nanoseconds since the start of the second.
+    pub fn nanosecond(&self) -> u32 {
+        (self.nanos % NANOS_PER_SECOND as u64) as u32
+    }
+
+    pub fn add_seconds(&self, seconds: i64) -> Self {
+        self.add_duration(ClockDuration::from_seconds(seconds.rem_euclid(MINUTES_PER_DAY * 60)))
+    }
+
+    pub fn add_duration(&self, duration: ClockDuration) -> Self {
+        // Whole days don't affect the result, and dropping them keeps the sum from overflowing.
+        let total = self.nanos as i64 + duration.as_nanos().rem_euclid(NANOS_PER_DAY);
+        PreciseClock {
+            clock: self.clock.add_minutes_i64(total.div_euclid(NANOS_PER_MINUTE)),
+            nanos: total.rem_euclid(NANOS_PER_MINUTE) as u64,
+        }
+    }
+
+    /// The clock with the seconds and below truncated.
+    pub fn to_clock(&self) -> Clock {
+        self.clock
+    }
+
+    fn nanos_of_day(&self) -> i64 {
+        self.to_clock().minutes_of_day() * NANOS_PER_MINUTE + self.nanos as i64
+    }
+
+    /// Signed distance from this clock to the other one, going whichever way round the dial is shorter.
+    ///
+    /// The result lies in the range from just above -12 hours to exactly 12 hours.
+    pub fn shortest_distance(&self, other: &PreciseClock) -> ClockDuration {
+        let forward = (other.nanos_of_day() - self.nanos_of_day()).rem_euclid(NANOS_PER_DAY);
+        if forward > NANOS_PER_DAY / 2 {
+            ClockDuration::from_nanos(forward - NANOS_PER_DAY)
+        } else {
+            ClockDuration::from_nanos(forward)
+        }
+    }
+}
+
+impl From<&Clock> for PreciseClock {
+    fn from(clock: &Clock) -> Self {
+        PreciseClock {
+            clock: *clock,
+            nanos: 0,
+        }
+    }
+}
+
+impl From<Clock> for PreciseClock {
+    fn from(clock: Clock) -> Self {
+        Self::from(&clock)
+    }
+}
+
+impl Add<ClockDuration> for PreciseClock {
+    type Output = Self;
+    fn add(self, duration: ClockDuration) -> Self {
+        self.add_duration(duration)
+    }
+}
+
+impl Sub<ClockDuration> for PreciseClock {
+    type Output = Self;
+    fn sub(self, duration: ClockDuration) -> Self {
+        self.add_duration(ClockDuration::from_nanos(-duration.as_nanos().rem_euclid(NANOS_PER_DAY)))
+    }
+}
+
+impl AddAssign<ClockDuration> for PreciseClock {
+    fn add_assign(&mut self, duration: ClockDuration) {
+        *self = *self + duration;
+    }
+}
+
+impl SubAssign<ClockDuration> for PreciseClock {
+    fn sub_assign(&mut self, duration: ClockDuration) {
+        *self = *self - duration;
+    }
+}
+
+/// Forward distance from `other` to `self`, i.e. how long it takes to get from `other` to `self`.
+impl Sub for PreciseClock {
+    type Output = ClockDuration;
+    fn sub(self, other: PreciseClock) -> ClockDuration {
+        ClockDuration::from_nanos((self.nanos_of_day() - other.nanos_of_day()).rem_euclid(NANOS_PER_DAY))
+    }
+}
+
+/// Formats the clock as `HH:MM:SS`, followed by the fraction of the second if it isn't zero.
+///
+/// The fraction has 3, 6 or 9 digits, whichever is enough to represent it exactly;
+/// the precision flag overrides this, e.g. `{:.1}` gives `08:30:15.2` and `{:.0}` drops the fraction.
+/// The extra digits are truncated, not rounded.
+impl Display for PreciseClock {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{:02}", self.to_clock(), self.second())?;
+        let nanos = self.nanosecond();
+        let digits = match f.precision() {
+            Some(precision) => precision.min(9),
+            None if nanos == 0 => 0,
+            None if nanos.is_multiple_of(1_000_000) => 3,
+            None if nanos.is_multiple_of(1_000) => 6,
+            None => 9,
+        };
+        if digits > 0 {
+            write!(f, ".{:0width$}", nanos / 10u32.pow(9 - digits as u32), width = digits)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,359 @@
+use crate::{Clock, ClockDuration, MINUTES_PER_DAY};
+use core::fmt::{self, Display, Formatter};
+use core::ops::Deref;
+
+/// Half-open interval of the times of day, from `start` up to but not including `end`.
+///
+/// The range may wrap past midnight, e.g. 22:00–06:00 contains 23:30 and 05:59, but not 06:00.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClockRange {
+    start: Clock,
+    /// Number of minutes in the range, up to a whole day.
+    length: u16,
+}
+
+impl ClockRange {
+    /// The range from `start` to `end`, which is empty if they are equal.
+    pub fn new(start: Clock, end: Clock) -> Self {
+        ClockRange {
+            start,
+            length: (end - start).minutes() as u16,
+        }
+    }
+
+    /// The whole day, beginning with `start`.
+    pub fn full_day(start: Clock) -> Self {
+        ClockRange {
+            start,
+            length: MINUTES_PER_DAY as u16,
+        }
+    }
+
+    pub fn start(&self) -> Clock {
+        self.start
+    }
+
+    /// The first clock after the range; equals `start` for the empty and the full-day ranges.
+    pub fn end(&self) -> Clock {
+        self.start + i32::from(self.length)
+    }
+
+    pub fn len(&self) -> ClockDuration {
+        ClockDuration::from_minutes(self.length.into())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn is_full_day(&self) -> bool {
+        i64::from(self.length) == MINUTES_PER_DAY
+    }
+
+    /// Checks whether the range passes midnight, i.e. ends on the next day.
+    pub fn wraps(&self) -> bool {
+        i64::from(self.start.minutes_since_midnight() + self.length) > MINUTES_PER_DAY
+    }
+
+    pub fn contains(&self, clock: &Clock) -> bool {
+        (*clock - self.start).minutes() < self.length.into()
+    }
+
+    /// Checks whether the ranges share at least one minute.
+    pub fn overlaps(&self, other: &ClockRange) -> bool {
+        // Two arcs of the dial overlap exactly when one of them contains the start of the other.
+        !self.is_empty() && !other.is_empty() && (self.contains(&other.start()) || other.contains(&self.start()))
+    }
+
+    /// The minutes in both ranges, which can form up to two ranges, e.g. 20:00–08:00 and 06:00–22:00
+    /// have 06:00–08:00 and 20:00–22:00 in common.
+    ///
+    /// The ranges are ordered by their start, and the result is empty if the ranges don't overlap.
+    pub fn intersection(&self, other: &ClockRange) -> ClockRanges {
+        if self.is_full_day() {
+            return other.non_empty();
+        }
+        if other.is_full_day() {
+            return self.non_empty();
+        }
+        let mut segments = [(0, 0); 4];
+        let mut count = 0;
+        for &(a_start, a_end) in self.segments().iter().flatten() {
+            for &(b_start, b_end) in other.segments().iter().flatten() {
+                let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+                if start < end {
+                    segments[count] = (start, end);
+                    count += 1;
+                }
+            }
+        }
+        segments[..count].sort_unstable();
+        from_segments(&segments[..count])
+    }
+
+    /// The minutes in either range, which can form up to two ranges.
+    ///
+    /// The ranges are ordered by their start. If together they cover the whole day,
+    /// the result is a single full-day range beginning with the start of `self`.
+    pub fn union(&self, other: &ClockRange) -> ClockRanges {
+        let [a, b] = self.segments();
+        let [c, d] = other.segments();
+        let mut segments = [a, b, c, d];
+        segments.sort_unstable();
+        let mut merged = [(0, 0); 4];
+        let mut count = 0;
+        for &(start, end) in segments.iter().flatten() {
+            match merged[..count].last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => {
+                    merged[count] = (start, end);
+                    count += 1;
+                }
+            }
+        }
+        if merged[..count] == [(0, MINUTES_PER_DAY)] {
+            return ClockRanges::one(ClockRange::full_day(self.start));
+        }
+        from_segments(&merged[..count])
+    }
+
+    /// Iterates over the range every `minutes`, starting with its start.
+    ///
+    /// # Panics
+    /// This function will panic if `minutes` is zero.
+    pub fn step_by(&self, minutes: u32) -> ClockSteps {
+        ClockSteps::new(self.start, self.length.into(), minutes, false)
+    }
+
+    /// Iterates over every minute in the range.
+    pub fn iter(&self) -> ClockRangeIter {
+        ClockRangeIter {
+            next: self.start,
+            remaining: self.length,
+        }
+    }
+
+    fn non_empty(&self) -> ClockRanges {
+        if self.is_empty() {
+            ClockRanges::EMPTY
+        } else {
+            ClockRanges::one(*self)
+        }
+    }
+
+    /// Splits the range at midnight into non-empty half-open intervals of minutes since midnight.
+    fn segments(&self) -> [Option<(i64, i64)>; 2] {
+        let start = self.start.minutes_of_day();
+        let end = start + i64::from(self.length);
+        if self.is_empty() {
+            [None, None]
+        } else if end > MINUTES_PER_DAY {
+            [Some((0, end - MINUTES_PER_DAY)), Some((start, MINUTES_PER_DAY))]
+        } else {
+            [Some((start, end)), None]
+        }
+    }
+}
+
+/// Turns sorted, disjoint intervals of minutes back into ranges, rejoining the ones split at midnight.
+fn from_segments(mut segments: &[(i64, i64)]) -> ClockRanges {
+    let mut morning_end = 0;
+    if segments.len() > 1 && segments[0].0 == 0 && segments[segments.len() - 1].1 == MINUTES_PER_DAY {
+        morning_end = segments[0].1;
+        segments = &segments[1..];
+    }
+    let mut ranges = ClockRanges::EMPTY;
+    for (i, &(start, end)) in segments.iter().enumerate() {
+        let end = if i == segments.len() - 1 { end + morning_end } else { end };
+        ranges.ranges[i] = ClockRange {
+            start: Clock::from_minutes_since_midnight(start),
+            length: (end - start) as u16,
+        };
+        ranges.len += 1;
+    }
+    ranges
+}
+
+/// Up to two disjoint ranges ordered by their start, returned by [ClockRange::intersection](struct.ClockRange.html#method.intersection)
+/// and [ClockRange::union](struct.ClockRange.html#method.union).
+///
+/// It dereferences to a slice of the ranges, and compares equal to an array of the same ranges.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClockRanges {
+    /// The ranges past `len` are always empty, so the derived comparisons only see the ones in use.
+    ranges: [ClockRange; 2],
+    len: usize,
+}
+
+impl ClockRanges {
+    const EMPTY: ClockRanges = ClockRanges {
+        ranges: [ClockRange {
+            start: Clock::MIDNIGHT,
+            length: 0,
+        }; 2],
+        len: 0,
+    };
+
+    fn one(range: ClockRange) -> Self {
+        let mut ranges = Self::EMPTY;
+        ranges.ranges[0] = range;
+        ranges.len = 1;
+        ranges
+    }
+
+    pub fn as_slice(&self) -> &[ClockRange] {
+        &self.ranges[..self.len]
+    }
+}
+
+impl Deref for ClockRanges {
+    type Target = [ClockRange];
+    fn deref(&self) -> &[ClockRange] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> PartialEq<[ClockRange; N]> for ClockRanges {
+    fn eq(&self, other: &[ClockRange; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<'a> IntoIterator for &'a ClockRanges {
+    type Item = &'a ClockRange;
+    type IntoIter = core::slice::Iter<'a, ClockRange>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl Display for ClockRange {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start(), self.end())
+    }
+}
+
+impl IntoIterator for &ClockRange {
+    type Item = Clock;
+    type IntoIter = ClockRangeIter;
+    fn into_iter(self) -> ClockRangeIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the minutes of a [ClockRange](struct.ClockRange.html).
+#[derive(Clone, Debug)]
+pub struct ClockRangeIter {
+    next: Clock,
+    remaining: u16,
+}
+
+impl Iterator for ClockRangeIter {
+    type Item = Clock;
+    fn next(&mut self) -> Option<Clock> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let clock = self.next;
+        self.next += 1;
+        self.remaining -= 1;
+        Some(clock)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining.into(), Some(self.remaining.into()))
+    }
+}
+
+impl DoubleEndedIterator for ClockRangeIter {
+    fn next_back(&mut self) -> Option<Clock> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.next + i32::from(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for ClockRangeIter {}
+
+impl Clock {
+    /// Iterates from `start` every `minutes` up to but not including `end`, wrapping past midnight if `end` is before `start`,
+    /// e.g. 22:30, 23:15, 00:00 and 00:45 from 22:30 to 01:00 every 45 minutes.
+    ///
+    /// The iteration is empty if `start` and `end` are equal; use [ClockRange::step_by](struct.ClockRange.html#method.step_by)
+    /// with a full-day range to go round the whole day.
+    ///
+    /// # Panics
+    /// This function will panic if `minutes` is zero.
+    pub fn step_by(start: Clock, end: Clock, minutes: u32) -> ClockSteps {
+        ClockSteps::new(start, (end - start).minutes(), minutes, false)
+    }
+
+    /// Like [step_by](#method.step_by), but also yields `end` if a step lands on it.
+    /// If `start` and `end` are equal, only `start` is yielded.
+    ///
+    /// # Panics
+    /// This function will panic if `minutes` is zero.
+    pub fn step_by_inclusive(start: Clock, end: Clock, minutes: u32) -> ClockSteps {
+        ClockSteps::new(start, (end - start).minutes(), minutes, true)
+    }
+}
+
+/// Iterator over the clocks a fixed number of minutes apart, returned by [Clock::step_by](struct.Clock.html#method.step_by).
+#[derive(Clone, Debug)]
+pub struct ClockSteps {
+    start: Clock,
+    step: i64,
+    /// Index of the next step from the front.
+    front: i64,
+    /// Index after the last step remaining from the back.
+    back: i64,
+}
+
+impl ClockSteps {
+    fn new(start: Clock, length: i64, minutes: u32, inclusive: bool) -> Self {
+        assert!(minutes > 0, "Clock step must be positive");
+        let step = i64::from(minutes);
+        let count = if inclusive { length / step + 1 } else { (length + step - 1) / step };
+        ClockSteps {
+            start,
+            step,
+            front: 0,
+            back: count,
+        }
+    }
+
+    fn at(&self, index: i64) -> Clock {
+        // The steps never reach beyond one day, so the offset can't overflow.
+        self.start + index * self.step
+    }
+}
+
+impl Iterator for ClockSteps {
+    type Item = Clock;
+    fn next(&mut self) -> Option<Clock> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.at(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for ClockSteps {
+    fn next_back(&mut self) -> Option<Clock> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.at(self.back))
+    }
+}
+
+impl ExactSizeIterator for ClockSteps {}
@@ -0,0 +1,292 @@
+use crate::{Clock, ClockRange, DayClock};
+use std::iter::FromIterator;
+use std::ops::BitOr;
+
+/// Day of the week. Day 0 of a [DayClock](struct.DayClock.html) timeline is a Monday.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// The weekday of the day with the given index, counting from a Monday at day 0.
+    pub fn of_day(day: i64) -> Self {
+        Weekday::ALL[day.rem_euclid(7) as usize]
+    }
+}
+
+/// Set of weekdays on which an [Alarm](struct.Alarm.html) is active.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Weekdays(u8);
+
+impl Weekdays {
+    pub const NONE: Weekdays = Weekdays(0);
+    pub const ALL: Weekdays = Weekdays(0b111_1111);
+    /// Monday to Friday.
+    pub const WORKDAYS: Weekdays = Weekdays(0b001_1111);
+    pub const WEEKEND: Weekdays = Weekdays(0b110_0000);
+
+    pub fn contains(self, day: Weekday) -> bool {
+        self.0 & Weekdays::from(day).0 != 0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self == Weekdays::NONE
+    }
+}
+
+impl From<Weekday> for Weekdays {
+    fn from(day: Weekday) -> Self {
+        Weekdays(1 << day as u8)
+    }
+}
+
+impl BitOr for Weekdays {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        Weekdays(self.0 | other.0)
+    }
+}
+
+impl BitOr<Weekday> for Weekdays {
+    type Output = Self;
+    fn bitor(self, other: Weekday) -> Self {
+        self | Weekdays::from(other)
+    }
+}
+
+impl BitOr for Weekday {
+    type Output = Weekdays;
+    fn bitor(self, other: Self) -> Weekdays {
+        Weekdays::from(self) | other
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Rule {
+    /// Once a day, at the given clock.
+    At(Clock),
+    /// From the start of the window, every `step` minutes while still inside it.
+    Every { step: u32, window: ClockRange },
+}
+
+/// Recurring alarm, going off at fixed clocks on the chosen weekdays.
+///
+/// The weekdays apply to the day on which each alarm goes off, so an alarm repeating within
+/// 22:00–02:00 on Fridays only goes off from 22:00 to 23:59 on Fridays, and from 00:00 on Saturdays
+/// if those are chosen too.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Alarm {
+    rule: Rule,
+    days: Weekdays,
+}
+
+impl Alarm {
+    /// Goes off once a day at the clock.
+    pub fn daily(clock: Clock) -> Self {
+        Alarm {
+            rule: Rule::At(clock),
+            days: Weekdays::ALL,
+        }
+    }
+
+    /// Goes off at the start of the window and then every `minutes` while still within it, e.g. at 09:00, 09:45
+    /// and 10:30 for 09:00–11:00 every 45 minutes.
+    ///
+    /// # Panics
+    /// This function will panic if `minutes` is zero.
+    pub fn every(minutes: u32, window: ClockRange) -> Self {
+        assert!(minutes > 0, "Alarm interval must be positive");
+        Alarm {
+            rule: Rule::Every { step: minutes, window },
+            days: Weekdays::ALL,
+        }
+    }
+
+    /// Limits the alarm to the given weekdays.
+    pub fn on<D: Into<Weekdays>>(self, days: D) -> Self {
+        Alarm {
+            days: days.into(),
+            ..self
+        }
+    }
+
+    pub fn days(&self) -> Weekdays {
+        self.days
+    }
+
+    /// Clocks at which the alarm goes off, sorted.
+    fn clocks(&self) -> Vec<Clock> {
+        let mut clocks = match self.rule {
+            Rule::At(clock) => vec![clock],
+            Rule::Every { step, ref window } => window.step_by(step).collect(),
+        };
+        clocks.sort_unstable();
+        clocks
+    }
+}
+
+/// Set of [alarms](struct.Alarm.html), answering when the next one goes off.
+///
+/// The queries taking a [Clock](struct.Clock.html) consider every alarm active on at least one weekday,
+/// and wrap around midnight; the ones taking a [DayClock](struct.DayClock.html) also honour the weekdays.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schedule {
+    alarms: Vec<Alarm>,
+}
+
+impl Schedule {
+    pub fn new() -> Self {
+        Schedule::default()
+    }
+
+    /// Adds the alarm to the schedule.
+    pub fn with(mut self, alarm: Alarm) -> Self {
+        self.add(alarm);
+        self
+    }
+
+    pub fn add(&mut self, alarm: Alarm) {
+        self.alarms.push(alarm);
+    }
+
+    pub fn alarms(&self) -> &[Alarm] {
+        &self.alarms
+    }
+
+    /// The first alarm strictly after the clock, possibly on the next day. Returns None if the schedule is empty.
+    pub fn next_after(&self, clock: &Clock) -> Option<Clock> {
+        let clocks = self.clocks(|days| !days.is_empty());
+        clocks.iter().find(|&next| next > clock).or_else(|| clocks.first()).copied()
+    }
+
+    /// The alarms from `start` up to but not including `end`, in the order they go off.
+    ///
+    /// As with [ClockRange](struct.ClockRange.html), the interval wraps past midnight if `end` is before `start`,
+    /// and it's empty if they are equal.
+    pub fn occurrences_between(&self, start: &Clock, end: &Clock) -> Vec<Clock> {
+        self.occurrences_in(&ClockRange::new(*start, *end))
+    }
+
+    /// The alarms within the range, in the order they go off.
+    pub fn occurrences_in(&self, range: &ClockRange) -> Vec<Clock> {
+        let mut clocks = self.clocks(|days| !days.is_empty());
+        clocks.retain(|clock| range.contains(clock));
+        clocks.sort_by_key(|&clock| clock - range.start());
+        clocks
+    }
+
+    /// The first alarm strictly after the moment, taking the weekdays into account.
+    /// Returns None if no alarm is active on any weekday, or if the next one falls after the last day.
+    pub fn next_after_day(&self, moment: &DayClock) -> Option<DayClock> {
+        // A week later every alarm comes round again, including the ones earlier on the same weekday.
+        (0..=7).find_map(|offset| {
+            let day = moment.day().checked_add(offset)?;
+            let clocks = self.clocks_on(Weekday::of_day(day));
+            let next = clocks.into_iter().find(|&clock| offset > 0 || clock > moment.clock())?;
+            Some(DayClock::new(day, next))
+        })
+    }
+
+    /// Iterates over the alarms from `start` up to but not including `end`, taking the weekdays into account.
+    ///
+    /// The alarms are found one at a time as the iteration goes on, so the span can be arbitrarily long.
+    pub fn occurrences_between_days(&self, start: &DayClock, end: &DayClock) -> Occurrences<'_> {
+        let first = if self.clocks_on(Weekday::of_day(start.day())).contains(&start.clock()) {
+            Some(*start)
+        } else {
+            self.next_after_day(start)
+        };
+        Occurrences {
+            schedule: self,
+            next: first,
+            end: *end,
+        }
+    }
+
+    /// The clocks at which alarms from both schedules go off on the same weekday, sorted.
+    pub fn collisions(&self, other: &Schedule) -> Vec<Clock> {
+        let mut collisions: Vec<Clock> = Weekday::ALL
+            .iter()
+            .flat_map(|&day| {
+                let theirs = other.clocks_on(day);
+                self.clocks_on(day)
+                    .into_iter()
+                    .filter(move |clock| theirs.binary_search(clock).is_ok())
+            })
+            .collect();
+        collisions.sort_unstable();
+        collisions.dedup();
+        collisions
+    }
+
+    /// Checks whether the schedules have any alarm going off at the same time on the same weekday.
+    pub fn collides_with(&self, other: &Schedule) -> bool {
+        !self.collisions(other).is_empty()
+    }
+
+    fn clocks_on(&self, day: Weekday) -> Vec<Clock> {
+        self.clocks(|days| days.contains(day))
+    }
+
+    /// Sorted and deduplicated clocks of the alarms with the matching weekdays.
+    fn clocks<F: Fn(Weekdays) -> bool>(&self, active: F) -> Vec<Clock> {
+        let mut clocks: Vec<Clock> = self
+            .alarms
+            .iter()
+            .filter(|alarm| active(alarm.days))
+            .flat_map(Alarm::clocks)
+            .collect();
+        clocks.sort_unstable();
+        clocks.dedup();
+        clocks
+    }
+}
+
+/// Iterator over the alarms of a schedule between two day clocks,
+/// returned by [Schedule::occurrences_between_days](struct.Schedule.html#method.occurrences_between_days).
+#[derive(Clone, Debug)]
+pub struct Occurrences<'a> {
+    schedule: &'a Schedule,
+    next: Option<DayClock>,
+    end: DayClock,
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = DayClock;
+    fn next(&mut self) -> Option<DayClock> {
+        let moment = self.next.filter(|moment| *moment < self.end)?;
+        self.next = self.schedule.next_after_day(&moment);
+        Some(moment)
+    }
+}
+
+impl From<Alarm> for Schedule {
+    fn from(alarm: Alarm) -> Self {
+        Schedule::new().with(alarm)
+    }
+}
+
+impl FromIterator<Alarm> for Schedule {
+    fn from_iter<I: IntoIterator<Item = Alarm>>(alarms: I) -> Self {
+        Schedule {
+            alarms: alarms.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! Spoken-word rendering of clocks, such as "quarter past eight" or "halb neun", behind the common
+//! [SpokenLanguage](trait.SpokenLanguage.html) trait.
+
+use crate::{Clock, MINUTES_PER_HOUR};
+
+/// Renders clocks the way people say the time in some language, e.g. "quarter past eight".
+pub trait SpokenLanguage {
+    /// The exact time, in words.
+    fn speak(&self, clock: Clock) -> String;
+
+    /// The word which marks an approximate time, e.g. "about".
+    fn approximately(&self) -> &'static str;
+
+    /// The time rounded to the nearest five minutes, marked as approximate unless it's exact,
+    /// e.g. "about ten past eight" for 08:11.
+    fn speak_fuzzy(&self, clock: Clock) -> String {
+        let minute = i64::from(clock.minute());
+        let rounded = clock + ((minute + 2) / 5 * 5 - minute);
+        if rounded == clock {
+            self.speak(clock)
+        } else {
+            format!("{} {}", self.approximately(), self.speak(rounded))
+        }
+    }
+}
+
+/// The hour which the minutes are counted towards, e.g. 9 for 08:40.
+fn next_hour(clock: Clock) -> u8 {
+    (clock.hour() + 1) % 24
+}
+
+/// Minutes left until the next hour.
+fn minutes_to(clock: Clock) -> usize {
+    MINUTES_PER_HOUR as usize - usize::from(clock.minute())
+}
+
+/// English, e.g. "quarter past eight", "twenty to ten", "midnight".
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct English;
+
+const ENGLISH_NUMBERS: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven", "twelve", "thirteen",
+    "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+impl English {
+    fn number(n: usize) -> String {
+        match n {
+            0..=19 => ENGLISH_NUMBERS[n].to_string(),
+            20 => "twenty".to_string(),
+            _ => format!("twenty-{}", ENGLISH_NUMBERS[n - 20]),
+        }
+    }
+
+    fn hour(hour: u8) -> String {
+        match hour {
+            0 => "midnight".to_string(),
+            12 => "noon".to_string(),
+            _ => Self::number(crate::hour12(hour) as usize),
+        }
+    }
+
+    fn minutes(n: usize) -> String {
+        match n {
+            15 => "quarter".to_string(),
+            1 => "one minute".to_string(),
+            _ if n.is_multiple_of(5) => Self::number(n),
+            _ => format!("{} minutes", Self::number(n)),
+        }
+    }
+}
+
+impl SpokenLanguage for English {
+    fn speak(&self, clock: Clock) -> String {
+        match clock.minute() {
+            0 if clock.hour().is_multiple_of(12) => Self::hour(clock.hour()),
+            0 => format!("{} o'clock", Self::hour(clock.hour())),
+            30 => format!("half past {}", Self::hour(clock.hour())),
+            minute @ 1..=29 => format!("{} past {}", Self::minutes(minute.into()), Self::hour(clock.hour())),
+            _ => format!("{} to {}", Self::minutes(minutes_to(clock)), Self::hour(next_hour(clock))),
+        }
+    }
+
+    fn approximately(&self) -> &'static str {
+        "about"
+    }
+}
+
+/// German, e.g. "Viertel nach acht", "halb neun", "fünf vor halb neun", "Mitternacht".
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct German;
+
+const GERMAN_NUMBERS: [&str; 20] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun", "zehn", "elf", "zwölf", "dreizehn",
+    "vierzehn", "fünfzehn", "sechzehn", "siebzehn", "achtzehn", "neunzehn",
+];
+
+impl German {
+    fn number(n: usize) -> String {
+        match n {
+            0..=19 => GERMAN_NUMBERS[n].to_string(),
+            20 => "zwanzig".to_string(),
+            21 => "einundzwanzig".to_string(),
+            _ => format!("{}undzwanzig", GERMAN_NUMBERS[n - 20]),
+        }
+    }
+
+    fn hour(hour: u8) -> String {
+        Self::number(crate::hour12(hour) as usize)
+    }
+
+    fn minutes(n: usize) -> String {
+        match n {
+            1 => "eine Minute".to_string(),
+            15 => "Viertel".to_string(),
+            _ if n.is_multiple_of(5) => Self::number(n),
+            _ => format!("{} Minuten", Self::number(n)),
+        }
+    }
+}
+
+impl SpokenLanguage for German {
+    fn speak(&self, clock: Clock) -> String {
+        let (hour, next) = (Self::hour(clock.hour()), Self::hour(next_hour(clock)));
+        match clock.minute() {
+            0 if clock.hour() == 0 => "Mitternacht".to_string(),
+            0 if clock.hour() % 12 == 1 => "ein Uhr".to_string(),
+            0 => format!("{} Uhr", hour),
+            // The half hour is named after the coming hour, and the minutes around it are counted from it.
+            30 => format!("halb {}", next),
+            25 => format!("fünf vor halb {}", next),
+            35 => format!("fünf nach halb {}", next),
+            minute @ 1..=29 => format!("{} nach {}", Self::minutes(minute.into()), hour),
+            _ => format!("{} vor {}", Self::minutes(minutes_to(clock)), next),
+        }
+    }
+
+    fn approximately(&self) -> &'static str {
+        "etwa"
+    }
+}
+
+/// French, e.g. "huit heures et quart", "neuf heures moins le quart", "midi et demi".
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct French;
+
+const FRENCH_NUMBERS: [&str; 17] = [
+    "zéro", "une", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze", "douze", "treize",
+    "quatorze", "quinze", "seize",
+];
+
+const FRENCH_TENS: [&str; 6] = ["", "dix", "vingt", "trente", "quarante", "cinquante"];
+
+impl French {
+    /// The number in the feminine, which both "heure" and "minute" need.
+    fn number(n: usize) -> String {
+        match (n / 10, n % 10) {
+            _ if n <= 16 => FRENCH_NUMBERS[n].to_string(),
+            (tens, 0) => FRENCH_TENS[tens].to_string(),
+            (1, ones) => format!("dix-{}", FRENCH_NUMBERS[ones]),
+            (tens, 1) => format!("{} et une", FRENCH_TENS[tens]),
+            (tens, ones) => format!("{}-{}", FRENCH_TENS[tens], FRENCH_NUMBERS[ones]),
+        }
+    }
+
+    fn hour(hour: u8) -> String {
+        match hour {
+            0 => "minuit".to_string(),
+            12 => "midi".to_string(),
+            _ if crate::hour12(hour) == 1 => "une heure".to_string(),
+            _ => format!("{} heures", Self::number(crate::hour12(hour) as usize)),
+        }
+    }
+}
+
+impl SpokenLanguage for French {
+    fn speak(&self, clock: Clock) -> String {
+        let hour = Self::hour(clock.hour());
+        match clock.minute() {
+            0 => hour,
+            15 => format!("{} et quart", hour),
+            // "Demi" agrees with the masculine "midi" and "minuit", and "demie" with the feminine "heure".
+            30 if clock.hour().is_multiple_of(12) => format!("{} et demi", hour),
+            30 => format!("{} et demie", hour),
+            45 => format!("{} moins le quart", Self::hour(next_hour(clock))),
+            minute if minute > 30 && minute.is_multiple_of(5) => {
+                format!("{} moins {}", Self::hour(next_hour(clock)), Self::number(minutes_to(clock)))
+            }
+            minute => format!("{} {}", hour, Self::number(minute.into())),
+        }
+    }
+
+    fn approximately(&self) -> &'static str {
+        "environ"
+    }
+}
+
+/// Russian, e.g. "четверть девятого", "половина девятого", "без двадцати девять", "полночь".
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Russian;
+
+/// Feminine numbers, as they agree with "минута".
+const RUSSIAN_NUMBERS: [&str; 20] = [
+    "ноль", "одна", "две", "три", "четыре", "пять", "шесть", "семь", "восемь", "девять", "десять", "одиннадцать",
+    "двенадцать", "тринадцать", "четырнадцать", "пятнадцать", "шестнадцать", "семнадцать", "восемнадцать",
+    "девятнадцать",
+];
+
+const RUSSIAN_GENITIVE: [&str; 20] = [
+    "ноля", "одной", "двух", "трёх", "четырёх", "пяти", "шести", "семи", "восьми", "девяти", "десяти", "одиннадцати",
+    "двенадцати", "тринадцати", "четырнадцати", "пятнадцати", "шестнадцати", "семнадцати", "восемнадцати",
+    "девятнадцати",
+];
+
+/// The hours in the nominative, used with "без".
+const RUSSIAN_HOURS: [&str; 13] = [
+    "", "час", "два", "три", "четыре", "пять", "шесть", "семь", "восемь", "девять", "десять", "одиннадцать", "двенадцать",
+];
+
+/// Genitive ordinals, naming the hour in progress, e.g. "девятого" for the ninth hour, from 08:00 to 09:00.
+const RUSSIAN_ORDINALS: [&str; 13] = [
+    "", "первого", "второго", "третьего", "четвёртого", "пятого", "шестого", "седьмого", "восьмого", "девятого", "десятого",
+    "одиннадцатого", "двенадцатого",
+];
+
+impl Russian {
+    fn compound(n: usize, words: &[&str; 20], twenty: &str) -> String {
+        match n {
+            0..=19 => words[n].to_string(),
+            20 => twenty.to_string(),
+            _ => format!("{} {}", twenty, words[n - 20]),
+        }
+    }
+
+    /// The form of a noun after the number: singular, paucal (2 to 4) or plural.
+    fn plural<'a>(n: usize, one: &'a str, few: &'a str, many: &'a str) -> &'a str {
+        match (n % 10, n % 100) {
+            (_, 11..=14) => many,
+            (1, _) => one,
+            (2..=4, _) => few,
+            _ => many,
+        }
+    }
+}
+
+impl SpokenLanguage for Russian {
+    fn speak(&self, clock: Clock) -> String {
+        let next = crate::hour12(next_hour(clock)) as usize;
+        match clock.minute() {
+            0 if clock.hour() == 0 => "полночь".to_string(),
+            0 if clock.hour() == 12 => "полдень".to_string(),
+            0 => {
+                let hour = crate::hour12(clock.hour()) as usize;
+                match hour {
+                    1 => "час".to_string(),
+                    _ => format!("{} {}", RUSSIAN_HOURS[hour], Self::plural(hour, "час", "часа", "часов")),
+                }
+            }
+            15 => format!("четверть {}", RUSSIAN_ORDINALS[next]),
+            30 => format!("половина {}", RUSSIAN_ORDINALS[next]),
+            45 => format!("без четверти {}", RUSSIAN_HOURS[next]),
+            minute @ 1..=29 => {
+                let minute = usize::from(minute);
+                let number = Self::compound(minute, &RUSSIAN_NUMBERS, "двадцать");
+                let noun = Self::plural(minute, "минута", "минуты", "минут");
+                format!("{} {} {}", number, noun, RUSSIAN_ORDINALS[next])
+            }
+            minute => {
+                let left = minutes_to(clock);
+                let number = Self::compound(left, &RUSSIAN_GENITIVE, "двадцати");
+                // The round numbers go without the noun: "без пяти девять", but "без трёх минут девять".
+                if minute.is_multiple_of(5) {
+                    format!("без {} {}", number, RUSSIAN_HOURS[next])
+                } else {
+                    let noun = if left % 10 == 1 && left != 11 { "минуты" } else { "минут" };
+                    format!("без {} {} {}", number, noun, RUSSIAN_HOURS[next])
+                }
+            }
+        }
+    }
+
+    fn approximately(&self) -> &'static str {
+        "примерно"
+    }
+}
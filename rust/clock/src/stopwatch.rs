@@ -0,0 +1,264 @@
+//! Stopwatches and countdown timers reading the time from a pluggable [TimeSource](trait.TimeSource.html).
+
+use crate::{Clock, ClockDuration, PreciseClock};
+use core::cell::Cell;
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+
+/// Source of monotonic time for [Stopwatch](struct.Stopwatch.html) and [Countdown](struct.Countdown.html).
+///
+/// Only the differences between readings matter, so the origin of the time is arbitrary.
+pub trait TimeSource {
+    /// Time elapsed since the origin of the source; it must never go backwards.
+    fn now(&self) -> ClockDuration;
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for &T {
+    fn now(&self) -> ClockDuration {
+        (**self).now()
+    }
+}
+
+/// The system's monotonic clock, measured from the moment the source was created.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug)]
+pub struct SystemTimeSource {
+    origin: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl SystemTimeSource {
+    pub fn new() -> Self {
+        SystemTimeSource {
+            origin: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for SystemTimeSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TimeSource for SystemTimeSource {
+    /// # Panics
+    /// This function will panic after about 292 years, when the time no longer fits in `ClockDuration`.
+    fn now(&self) -> ClockDuration {
+        ClockDuration::try_from(self.origin.elapsed()).expect("ClockDuration overflow")
+    }
+}
+
+/// Time which only moves when told to, for driving timers in tests or from an external tick.
+///
+/// Timers borrow it through the `&T` implementation of [TimeSource](trait.TimeSource.html),
+/// so it can still be advanced while they use it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ManualTimeSource {
+    now: Cell<ClockDuration>,
+}
+
+impl ManualTimeSource {
+    /// A source standing at its origin.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the time forward.
+    ///
+    /// # Panics
+    /// This function will panic if `duration` is negative, as the time can't go backwards.
+    pub fn advance(&self, duration: ClockDuration) {
+        assert!(!duration.is_negative(), "Time can't go backwards");
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl TimeSource for ManualTimeSource {
+    fn now(&self) -> ClockDuration {
+        self.now.get()
+    }
+}
+
+/// A lap recorded by [Stopwatch::lap](struct.Stopwatch.html#method.lap).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Lap {
+    /// Number of the lap, starting from 1.
+    pub number: u32,
+    /// Time since the previous lap, or since the start for the first one.
+    pub time: ClockDuration,
+    /// Total time elapsed when the lap was recorded.
+    pub split: ClockDuration,
+}
+
+/// Stopwatch measuring the time its source runs while it's not paused.
+///
+/// A new stopwatch stands at zero until [start](#method.start)ed.
+#[derive(Clone, Debug)]
+pub struct Stopwatch<S: TimeSource> {
+    source: S,
+    /// Time measured before the current run.
+    accumulated: ClockDuration,
+    /// Reading of the source when the current run began, None while paused or stopped.
+    running_since: Option<ClockDuration>,
+    laps: u32,
+    last_split: ClockDuration,
+}
+
+impl<S: TimeSource> Stopwatch<S> {
+    pub fn new(source: S) -> Self {
+        Stopwatch {
+            source,
+            accumulated: ClockDuration::ZERO,
+            running_since: None,
+            laps: 0,
+            last_split: ClockDuration::ZERO,
+        }
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Starts measuring from zero, discarding any earlier time and laps.
+    pub fn start(&mut self) {
+        self.reset();
+        self.running_since = Some(self.source.now());
+    }
+
+    /// Stops measuring, keeping the time so far; does nothing if already paused.
+    pub fn pause(&mut self) {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated = self.accumulated + (self.source.now() - since);
+        }
+    }
+
+    /// Continues measuring after a [pause](#method.pause); does nothing if already running.
+    pub fn resume(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(self.source.now());
+        }
+    }
+
+    /// Records a lap, which also works while paused, and returns it.
+    pub fn lap(&mut self) -> Lap {
+        self.lap_at(self.elapsed())
+    }
+
+    fn lap_at(&mut self, split: ClockDuration) -> Lap {
+        let time = split - self.last_split;
+        self.laps += 1;
+        self.last_split = split;
+        Lap {
+            number: self.laps,
+            time,
+            split,
+        }
+    }
+
+    /// Stops the stopwatch and sets it back to zero, with no laps.
+    pub fn reset(&mut self) {
+        self.accumulated = ClockDuration::ZERO;
+        self.running_since = None;
+        self.laps = 0;
+        self.last_split = ClockDuration::ZERO;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Number of laps recorded since the start.
+    pub fn laps(&self) -> u32 {
+        self.laps
+    }
+
+    pub fn elapsed(&self) -> ClockDuration {
+        match self.running_since {
+            Some(since) => self.accumulated + (self.source.now() - since),
+            None => self.accumulated,
+        }
+    }
+
+    /// The elapsed time as shown on a clock starting at midnight, wrapping around after 24 hours.
+    pub fn elapsed_clock(&self) -> PreciseClock {
+        PreciseClock::from(Clock::MIDNIGHT) + self.elapsed()
+    }
+}
+
+/// Countdown from a fixed duration, finishing when no time remains.
+///
+/// It runs on a [Stopwatch](struct.Stopwatch.html), and shares its operations.
+#[derive(Clone, Debug)]
+pub struct Countdown<S: TimeSource> {
+    duration: ClockDuration,
+    stopwatch: Stopwatch<S>,
+}
+
+impl<S: TimeSource> Countdown<S> {
+    /// # Panics
+    /// This function will panic if `duration` is negative.
+    pub fn new(source: S, duration: ClockDuration) -> Self {
+        assert!(!duration.is_negative(), "Countdown duration can't be negative");
+        Countdown {
+            duration,
+            stopwatch: Stopwatch::new(source),
+        }
+    }
+
+    /// The duration counted down from.
+    pub fn duration(&self) -> ClockDuration {
+        self.duration
+    }
+
+    /// Starts counting down from the full duration.
+    pub fn start(&mut self) {
+        self.stopwatch.start();
+    }
+
+    pub fn pause(&mut self) {
+        self.stopwatch.pause();
+    }
+
+    pub fn resume(&mut self) {
+        self.stopwatch.resume();
+    }
+
+    /// Records a lap, as on a [Stopwatch](struct.Stopwatch.html#method.lap); the time stops counting at the end
+    /// of the countdown, so the laps recorded after it are empty.
+    pub fn lap(&mut self) -> Lap {
+        let split = self.elapsed();
+        self.stopwatch.lap_at(split)
+    }
+
+    /// Stops the countdown and sets it back to the full duration.
+    pub fn reset(&mut self) {
+        self.stopwatch.reset();
+    }
+
+    /// Whether the countdown is running and hasn't finished yet.
+    pub fn is_running(&self) -> bool {
+        self.stopwatch.is_running() && !self.is_finished()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.stopwatch.elapsed() >= self.duration
+    }
+
+    /// Time counted down so far, never more than the duration.
+    pub fn elapsed(&self) -> ClockDuration {
+        self.stopwatch.elapsed().min(self.duration)
+    }
+
+    /// Time left until the countdown finishes, zero once it has.
+    pub fn remaining(&self) -> ClockDuration {
+        self.duration - self.elapsed()
+    }
+
+    /// The remaining time as shown on a clock starting at midnight, e.g. 00:05:00 with five minutes left.
+    pub fn remaining_clock(&self) -> PreciseClock {
+        PreciseClock::from(Clock::MIDNIGHT) + self.remaining()
+    }
+}
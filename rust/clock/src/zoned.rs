@@ -0,0 +1,120 @@
+use crate::parse::{self, ParseClockError, ParseErrorKind};
+use crate::{Clock, MINUTES_PER_DAY, MINUTES_PER_HOUR};
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+/// Largest supported offset from UTC in minutes, 23:59.
+const MAX_OFFSET: i32 = (MINUTES_PER_DAY - 1) as i32;
+
+/// Fixed offset from UTC, within ±23:59.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcOffset {
+    minutes: i32,
+}
+
+impl UtcOffset {
+    pub const UTC: UtcOffset = UtcOffset { minutes: 0 };
+
+    /// Returns None if the offset isn't within ±23:59.
+    pub fn from_minutes(minutes: i32) -> Option<Self> {
+        if (-MAX_OFFSET..=MAX_OFFSET).contains(&minutes) {
+            Some(UtcOffset { minutes })
+        } else {
+            None
+        }
+    }
+
+    /// The offset of `hours` and `minutes`, both of which carry the sign, e.g. `from_hm(-3, -30)` for -03:30.
+    ///
+    /// Returns None if the offset isn't within ±23:59.
+    pub fn from_hm(hours: i32, minutes: i32) -> Option<Self> {
+        Self::from_minutes(hours.checked_mul(MINUTES_PER_HOUR as i32)?.checked_add(minutes)?)
+    }
+
+    /// Total minutes ahead of UTC, negative for the zones behind it.
+    pub fn minutes(self) -> i32 {
+        self.minutes
+    }
+}
+
+/// Formats the offset as `+HH:MM` or `-HH:MM`; UTC itself is `+00:00`.
+impl Display for UtcOffset {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let sign = if self.minutes < 0 { '-' } else { '+' };
+        let minutes = self.minutes.unsigned_abs();
+        write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+/// Parses `Z`, `±HH`, `±HHMM` or `±HH:MM`.
+impl FromStr for UtcOffset {
+    type Err = ParseClockError;
+    fn from_str(input: &str) -> Result<Self, ParseClockError> {
+        parse::parse_offset(input).map(|minutes| UtcOffset { minutes })
+    }
+}
+
+/// [Clock](struct.Clock.html) in a time zone with a fixed offset from UTC.
+///
+/// Two zoned clocks are equal only if both their clocks and their offsets are;
+/// use [same_time](#method.same_time) to compare the moments they show.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ZonedClock {
+    /// The local clock.
+    clock: Clock,
+    offset: UtcOffset,
+}
+
+impl ZonedClock {
+    pub fn new(clock: Clock, offset: UtcOffset) -> Self {
+        ZonedClock { clock, offset }
+    }
+
+    /// The local clock.
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    pub fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Shows the same moment in the zone with the given offset.
+    ///
+    /// Also returns the day carry: -1 if the converted clock falls on the previous day, 1 if on the next one, 0 otherwise.
+    pub fn to_offset(&self, offset: UtcOffset) -> (ZonedClock, i64) {
+        let (clock, days) = self.clock.overflowing_add_minutes(i64::from(offset.minutes - self.offset.minutes));
+        (ZonedClock { clock, offset }, days)
+    }
+
+    /// Shows the same moment in UTC, together with the day carry as in [to_offset](#method.to_offset).
+    pub fn to_utc(&self) -> (Clock, i64) {
+        let (utc, days) = self.to_offset(UtcOffset::UTC);
+        (utc.clock(), days)
+    }
+
+    /// Checks whether both clocks show the same moment on the same day, regardless of their offsets.
+    pub fn same_time(&self, other: &ZonedClock) -> bool {
+        self.to_offset(UtcOffset::UTC) == other.to_offset(UtcOffset::UTC)
+    }
+}
+
+/// Formats the clock followed by its offset, e.g. `08:30+05:30`.
+impl Display for ZonedClock {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.clock(), self.offset)
+    }
+}
+
+/// Parses a clock in any format supported by [Clock](struct.Clock.html#impl-FromStr), followed by a required UTC offset,
+/// e.g. `08:30+05:30`, `0830-0800` or `T08:30Z`.
+impl FromStr for ZonedClock {
+    type Err = ParseClockError;
+    fn from_str(input: &str) -> Result<Self, ParseClockError> {
+        let parsed = parse::parse(input)?;
+        let minutes = parsed
+            .offset
+            .ok_or_else(|| ParseClockError::new(parsed.end, ParseErrorKind::MissingOffset))?;
+        Ok(ZonedClock::new(parsed.to_clock()?, UtcOffset { minutes }))
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc};
+use clock::{Clock, PreciseClock, UtcOffset, ZonedClock};
+
+#[test]
+fn test_clock_to_naive_time() {
+    assert_eq!(NaiveTime::from(Clock::new(8, 30)), NaiveTime::from_hms(8, 30, 0));
+    assert_eq!(NaiveTime::from(Clock::new(25, -1)), NaiveTime::from_hms(0, 59, 0));
+}
+
+#[test]
+fn test_naive_time_to_clock_truncates() {
+    assert_eq!(Clock::from(NaiveTime::from_hms_milli(23, 59, 59, 999)), Clock::new(23, 59));
+}
+
+#[test]
+fn test_precise_round_trip() {
+    let clock = PreciseClock::with_nanos(13, 5, 42, 123_456_789);
+    let time = NaiveTime::from(clock);
+    assert_eq!(time, NaiveTime::from_hms_nano(13, 5, 42, 123_456_789));
+    assert_eq!(PreciseClock::from(time), clock);
+}
+
+#[test]
+fn test_leap_second() {
+    let leap = NaiveTime::from_hms_nano(23, 59, 59, 1_500_000_000);
+    assert_eq!(PreciseClock::from(leap), PreciseClock::with_nanos(23, 59, 59, 999_999_999));
+}
+
+#[test]
+fn test_from_datetime() {
+    let offset = FixedOffset::east(5 * 3600 + 30 * 60);
+    let datetime = offset.ymd(2019, 3, 1).and_hms_milli(7, 45, 10, 500);
+    assert_eq!(Clock::from(&datetime), Clock::new(7, 45));
+    assert_eq!(PreciseClock::from(&datetime), PreciseClock::with_nanos(7, 45, 10, 500_000_000));
+    let expected = ZonedClock::new(Clock::new(7, 45), UtcOffset::from_hm(5, 30).unwrap());
+    assert_eq!(ZonedClock::from(&datetime), expected);
+}
+
+#[test]
+fn test_from_utc_datetime() {
+    let datetime = Utc.ymd(2019, 3, 1).and_hms(23, 0, 0);
+    assert_eq!(ZonedClock::from(&datetime), ZonedClock::new(Clock::new(23, 0), UtcOffset::UTC));
+}
+
+#[test]
+fn test_combine_with_date() {
+    let date = NaiveDate::from_ymd(2020, 2, 29);
+    assert_eq!(Clock::new(18, 15).on(date), date.and_hms(18, 15, 0));
+    assert_eq!(PreciseClock::new(0, 0, 1).on(date), date.and_hms(0, 0, 1));
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "std")]
+
+use clock::cli::{run, CliError, Output};
+use clock::{Clock, ClockDuration};
+
+fn text(args: &[&str]) -> String {
+    run(args).unwrap().to_text()
+}
+
+fn json(args: &[&str]) -> String {
+    run(args).unwrap().to_json()
+}
+
+#[test]
+fn test_add() {
+    assert_eq!(text(&["add", "23:50", "+45m"]), "00:35 (+1 day)");
+    assert_eq!(text(&["add", "08:00", "1h30m"]), "09:30");
+    assert_eq!(text(&["add", "08:00", "-9h"]), "23:00 (-1 day)");
+    assert_eq!(text(&["add", "08:00", "72h"]), "08:00 (+3 days)");
+    assert_eq!(json(&["add", "23:50", "+45m"]), r#"{"clock":"00:35","days":1}"#);
+}
+
+#[test]
+fn test_sub() {
+    assert_eq!(
+        run(&["sub", "00:10", "1h15m"]),
+        Ok(Output::Moved {
+            clock: Clock::new(22, 55),
+            days: -1,
+        })
+    );
+    assert_eq!(text(&["sub", "8:30 pm", "90s"]), "20:28");
+}
+
+#[test]
+fn test_diff() {
+    assert_eq!(text(&["diff", "22:00", "06:30"]), "8h30m");
+    assert_eq!(
+        json(&["diff", "06:30", "22:00"]),
+        r#"{"from":"06:30","to":"22:00","forward":"15h30m","forward_minutes":930,"shortest":"-8h30m","shortest_minutes":-510}"#
+    );
+}
+
+#[test]
+fn test_convert() {
+    assert_eq!(text(&["convert", "14:00", "+02:00", "-05:00"]), "07:00-05:00");
+    assert_eq!(text(&["convert", "23:30", "Z", "+05:30"]), "05:00+05:30 (+1 day)");
+    assert_eq!(
+        json(&["convert", "01:00", "+02:00", "-05:00"]),
+        r#"{"clock":"18:00","offset":"-05:00","utc":"23:00","days":-1}"#
+    );
+}
+
+#[test]
+fn test_range() {
+    assert_eq!(text(&["range", "22:00", "06:00"]), "22:00-06:00 (8h)");
+    assert_eq!(text(&["range", "22:00", "02:00", "--step", "90m"]), "22:00-02:00 (4h)\n22:00\n23:30\n01:00");
+    assert_eq!(
+        json(&["range", "09:00", "10:00", "--step", "30m"]),
+        r#"{"start":"09:00","end":"10:00","length":"1h","length_minutes":60,"wraps":false,"steps":["09:00","09:30"]}"#
+    );
+    assert_eq!(
+        json(&["range", "09:00", "09:00"]),
+        r#"{"start":"09:00","end":"09:00","length":"0m","length_minutes":0,"wraps":false}"#
+    );
+}
+
+#[test]
+fn test_between() {
+    assert_eq!(text(&["between", "23:30", "22:00", "06:00"]), "true");
+    assert_eq!(text(&["between", "06:00", "22:00", "06:00"]), "false");
+    assert_eq!(
+        json(&["between", "01:00", "22:00", "06:00"]),
+        r#"{"clock":"01:00","start":"22:00","end":"06:00","contained":true}"#
+    );
+}
+
+#[test]
+fn test_format() {
+    assert_eq!(text(&["format", "14:05", "%I:%M %p"]), "02:05 PM");
+    assert_eq!(json(&["format", "14:05", "\"%R\"\n"]), r#"{"clock":"14:05","formatted":"\"14:05\"\u000a"}"#);
+}
+
+#[test]
+fn test_durations() {
+    let minutes = |argument: &str| match run(&["add", "00:00", argument]) {
+        Ok(Output::Moved { clock, days }) => Some(days * 1440 + i64::from(clock.minutes_since_midnight())),
+        _ => None,
+    };
+    assert_eq!(minutes("45m"), Some(45));
+    assert_eq!(minutes("+2h"), Some(120));
+    assert_eq!(minutes("1h1m60s"), Some(62));
+    assert_eq!(minutes("-1m"), Some(-1));
+    assert_eq!(minutes("-61s"), Some(-2));
+    for invalid in &["", "+", "45", "m", "1h30", "1d", "1.5h", "--1m", "99999999999999999h"] {
+        assert_eq!(minutes(invalid), None, "{:?}", invalid);
+    }
+}
+
+#[test]
+fn test_invalid_arguments() {
+    assert_eq!(
+        run(&["add", "25:00", "1m"]),
+        Err(CliError::InvalidArgument {
+            argument: "25:00".to_string(),
+            reason: "hour out of range at position 0".to_string(),
+        })
+    );
+    assert!(matches!(run(&["convert", "10:00", "+25:00", "Z"]), Err(CliError::InvalidArgument { .. })));
+    assert!(matches!(run(&["format", "10:00", "%Q"]), Err(CliError::InvalidArgument { .. })));
+    assert!(matches!(run(&["range", "10:00", "11:00", "--step", "0m"]), Err(CliError::InvalidArgument { .. })));
+    assert!(matches!(run(&["range", "10:00", "11:00", "--step", "24h"]), Err(CliError::InvalidArgument { .. })));
+}
+
+#[test]
+fn test_usage_errors() {
+    let empty: [&str; 0] = [];
+    assert_eq!(run(&empty), Err(CliError::Usage("missing command".to_string())));
+    assert_eq!(run(&["diff", "10:00"]), Err(CliError::Usage("wrong arguments for \"diff\"".to_string())));
+    assert_eq!(run(&["now"]), Err(CliError::Usage("unknown command \"now\"".to_string())));
+    assert_eq!(
+        CliError::InvalidArgument {
+            argument: "x".to_string(),
+            reason: "bad".to_string(),
+        }
+        .to_string(),
+        "invalid argument \"x\": bad"
+    );
+}
+
+#[test]
+fn test_outputs_own_their_values() {
+    let output = run(&["range", "23:00", "01:00", "--step", "1h"]).unwrap();
+    match output {
+        Output::Range { range, steps } => {
+            assert_eq!(range.len(), ClockDuration::from_minutes(120));
+            assert_eq!(steps, Some(vec![Clock::new(23, 0), Clock::new(0, 0)]));
+        }
+        other => panic!("unexpected output {:?}", other),
+    }
+}
@@ -1,4 +1,4 @@
-use clock::Clock;
+use clock::{clock, Clock};
 
 //
 // Clock Creation
@@ -287,3 +287,153 @@ fn test_compare_clocks_with_negative_hours_and_minutes_that_wrap() {
 fn test_compare_full_clock_and_zeroed_clock() {
     assert_eq!(Clock::new(24, 0), Clock::new(0, 0))
 }
+
+//
+// Operators
+//
+
+#[test]
+fn test_add_operator() {
+    assert_eq!(Clock::new(23, 50) + 45, Clock::new(0, 35));
+}
+
+#[test]
+fn test_sub_operator() {
+    assert_eq!(Clock::new(0, 10) - 45, Clock::new(23, 25));
+}
+
+#[test]
+fn test_add_assign_and_sub_assign() {
+    let mut clock = Clock::new(12, 0);
+    clock += 90;
+    clock -= 30i64;
+    assert_eq!(clock, Clock::new(13, 0));
+}
+
+#[test]
+fn test_add_i64_beyond_i32_range() {
+    let minutes = 10 * 1440 * i64::from(i32::MAX) + 61;
+    assert_eq!((Clock::new(0, 0) + minutes).to_string(), "01:01");
+    assert_eq!((Clock::new(0, 0) - minutes).to_string(), "22:59");
+}
+
+#[test]
+fn test_extreme_minute_counts() {
+    assert_eq!((Clock::new(0, 0) + i64::MAX).to_string(), "18:07");
+    assert_eq!((Clock::new(0, 0) + i64::MIN).to_string(), "05:52");
+    assert_eq!((Clock::new(0, 0) - i64::MIN).to_string(), "18:08");
+    assert_eq!(Clock::new(i32::MIN, i32::MIN).to_string(), "13:52");
+}
+
+//
+// Ordering and Hashing
+//
+
+#[test]
+fn test_clocks_are_ordered_by_minutes_since_midnight() {
+    let mut clocks = [Clock::new(23, 59), Clock::new(0, 0), Clock::new(12, 30), Clock::new(12, 5)];
+    clocks.sort();
+    let sorted: Vec<String> = clocks.iter().map(Clock::to_string).collect();
+    assert_eq!(sorted, ["00:00", "12:05", "12:30", "23:59"]);
+    assert!(Clock::new(8, 59) < Clock::new(9, 0));
+}
+
+#[test]
+fn test_clocks_as_map_keys() {
+    use std::collections::{BTreeMap, HashMap};
+    let mut hashed = HashMap::new();
+    hashed.insert(Clock::new(8, 0), "breakfast");
+    assert_eq!(hashed.get(&Clock::new(32, 0)), Some(&"breakfast"));
+    let ordered: BTreeMap<Clock, &str> = vec![(Clock::new(19, 0), "dinner"), (Clock::new(8, 0), "breakfast")]
+        .into_iter()
+        .collect();
+    assert_eq!(ordered.values().collect::<Vec<_>>(), [&"breakfast", &"dinner"]);
+}
+
+#[test]
+fn test_clock_is_copy() {
+    let clock = Clock::new(10, 0);
+    let later = clock + 30;
+    assert_eq!(clock, Clock::new(10, 0));
+    assert_eq!(later, Clock::new(10, 30));
+}
+
+#[test]
+fn test_is_between() {
+    assert!(Clock::new(10, 0).is_between(Clock::new(9, 0), Clock::new(17, 0)));
+    assert!(Clock::new(9, 0).is_between(Clock::new(9, 0), Clock::new(17, 0)));
+    assert!(!Clock::new(17, 0).is_between(Clock::new(9, 0), Clock::new(17, 0)));
+    assert!(Clock::new(23, 0).is_between(Clock::new(22, 0), Clock::new(2, 0)));
+    assert!(Clock::new(1, 59).is_between(Clock::new(22, 0), Clock::new(2, 0)));
+    assert!(!Clock::new(12, 0).is_between(Clock::new(22, 0), Clock::new(2, 0)));
+    assert!(!Clock::new(12, 0).is_between(Clock::new(12, 0), Clock::new(12, 0)));
+}
+
+#[test]
+fn test_cyclic_cmp() {
+    use std::cmp::Ordering;
+    let origin = Clock::new(22, 0);
+    assert_eq!(Clock::new(23, 0).cyclic_cmp(&Clock::new(1, 0), origin), Ordering::Less);
+    assert_eq!(Clock::new(21, 0).cyclic_cmp(&Clock::new(1, 0), origin), Ordering::Greater);
+    assert_eq!(Clock::new(22, 0).cyclic_cmp(&Clock::new(46, 0), origin), Ordering::Equal);
+    let mut shift = [Clock::new(2, 0), Clock::new(23, 30), Clock::new(0, 15)];
+    shift.sort_by(|a, b| a.cyclic_cmp(b, origin));
+    assert_eq!(shift, [Clock::new(23, 30), Clock::new(0, 15), Clock::new(2, 0)]);
+}
+
+#[test]
+fn test_constants_and_accessors() {
+    assert_eq!(Clock::MIDNIGHT, Clock::new(0, 0));
+    assert_eq!(Clock::NOON, Clock::new(12, 0));
+    let clock = Clock::new(13, 45);
+    assert_eq!((clock.hour(), clock.minute()), (13, 45));
+    assert_eq!(clock.minutes_since_midnight(), 825);
+    assert_eq!(Clock::from_minutes_since_midnight(825), clock);
+    assert_eq!(Clock::from_minutes_since_midnight(-1), Clock::new(23, 59));
+}
+
+#[test]
+fn test_const_constructors() {
+    const OPENING: Clock = Clock::from_hm(8, 30);
+    const WRAPPED: Clock = Clock::new(25, -30);
+    assert_eq!(OPENING, Clock::new(8, 30));
+    assert_eq!(WRAPPED, Clock::new(0, 30));
+    assert_eq!(Clock::checked_from_hm(23, 59), Some(Clock::new(23, 59)));
+    assert_eq!(Clock::checked_from_hm(24, 0), None);
+    assert_eq!(Clock::checked_from_hm(8, 60), None);
+}
+
+#[test]
+#[should_panic]
+fn test_from_hm_out_of_range() {
+    Clock::from_hm(8, 60);
+}
+
+#[test]
+fn test_clock_macro() {
+    const CLOSING: Clock = clock!(17:45);
+    assert_eq!(CLOSING, Clock::new(17, 45));
+    assert_eq!(clock!(08:05), Clock::new(8, 5));
+    assert_eq!(clock!(00:00), Clock::MIDNIGHT);
+    assert_eq!(clock!(25:00, wrapping), clock!(01:00));
+    assert_eq!(clock!(-1:-15, wrapping), clock!(22:45));
+}
+
+#[test]
+fn test_compact_representation() {
+    assert_eq!(std::mem::size_of::<Clock>(), 2);
+    assert_eq!(std::mem::size_of::<Option<Clock>>(), 4);
+}
+
+#[test]
+fn test_wrapping_matches_modular_arithmetic() {
+    let clocks: Vec<Clock> = (0..1440).step_by(7).map(Clock::from_minutes_since_midnight).collect();
+    for &minutes in &[0i64, 1, 59, 1439, 1440, 2881, -1, -1441, i64::MAX, i64::MIN] {
+        for clock in &clocks {
+            let expected = (i64::from(clock.minutes_since_midnight()) + minutes.rem_euclid(1440)) % 1440;
+            assert_eq!((*clock + minutes).minutes_since_midnight() as i64, expected);
+            let back = (i64::from(clock.minutes_since_midnight()) - expected).rem_euclid(1440);
+            assert_eq!((*clock - Clock::from_minutes_since_midnight(expected)).as_nanos(), back * 60_000_000_000);
+        }
+    }
+}
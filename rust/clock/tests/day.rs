@@ -0,0 +1,82 @@
+use clock::{Clock, ClockDuration, DayClock};
+
+#[test]
+fn test_overflowing_add_within_the_day() {
+    assert_eq!(Clock::new(8, 0).overflowing_add_minutes(90), (Clock::new(9, 30), 0));
+    assert_eq!(Clock::new(8, 0).overflowing_add_minutes(-480), (Clock::new(0, 0), 0));
+}
+
+#[test]
+fn test_overflowing_add_past_midnight() {
+    assert_eq!(Clock::new(23, 50).overflowing_add_minutes(45), (Clock::new(0, 35), 1));
+    assert_eq!(Clock::new(23, 50).overflowing_add_minutes(10), (Clock::new(0, 0), 1));
+    assert_eq!(Clock::new(6, 0).overflowing_add_minutes(3 * 1440), (Clock::new(6, 0), 3));
+}
+
+#[test]
+fn test_overflowing_add_backwards() {
+    assert_eq!(Clock::new(0, 10).overflowing_add_minutes(-20), (Clock::new(23, 50), -1));
+    assert_eq!(Clock::new(0, 0).overflowing_add_minutes(-1440), (Clock::new(0, 0), -1));
+    assert_eq!(Clock::new(0, 0).overflowing_add_minutes(-1441), (Clock::new(23, 59), -2));
+}
+
+#[test]
+fn test_overflowing_sub() {
+    assert_eq!(Clock::new(0, 10).overflowing_sub_minutes(20), (Clock::new(23, 50), -1));
+    assert_eq!(Clock::new(23, 0).overflowing_sub_minutes(-120), (Clock::new(1, 0), 1));
+    assert_eq!(Clock::new(12, 0).overflowing_sub_minutes(60), (Clock::new(11, 0), 0));
+}
+
+#[test]
+fn test_overflowing_extremes() {
+    let days_max = i64::MAX / 1440;
+    assert_eq!(Clock::new(0, 0).overflowing_add_minutes(i64::MAX).1, days_max);
+    assert_eq!(Clock::new(0, 0).overflowing_sub_minutes(i64::MIN), (Clock::new(18, 8), days_max));
+}
+
+#[test]
+fn test_overflowing_add_duration() {
+    let duration = ClockDuration::from_seconds(30 * 60 + 59);
+    assert_eq!(Clock::new(23, 30).overflowing_add(duration), (Clock::new(0, 0), 1));
+    assert_eq!(Clock::new(0, 0).overflowing_add(-ClockDuration::from_seconds(1)), (Clock::new(23, 59), -1));
+}
+
+#[test]
+fn test_day_clock_add_minutes() {
+    let start = DayClock::new(0, Clock::new(22, 0));
+    assert_eq!(start + 150, DayClock::new(1, Clock::new(0, 30)));
+    assert_eq!(start - 1440 * 2, DayClock::new(-2, Clock::new(22, 0)));
+    let mut shift = start;
+    shift += 600;
+    assert_eq!((shift.day(), shift.clock()), (1, Clock::new(8, 0)));
+    shift -= 600;
+    assert_eq!(shift, start);
+}
+
+#[test]
+fn test_day_clock_duration() {
+    let start = DayClock::new(0, Clock::new(22, 0));
+    assert_eq!(start + ClockDuration::from_seconds(3 * 3600 + 1), DayClock::new(1, Clock::new(1, 0)));
+    assert_eq!(start - ClockDuration::from_seconds(1), DayClock::new(0, Clock::new(21, 59)));
+    assert_eq!(DayClock::new(1, Clock::new(6, 0)) - start, ClockDuration::from_minutes(480));
+    assert_eq!(start - DayClock::new(1, Clock::new(6, 0)), ClockDuration::from_minutes(-480));
+}
+
+#[test]
+fn test_day_clock_order() {
+    let mut timeline = [
+        DayClock::new(1, Clock::new(1, 0)),
+        DayClock::new(0, Clock::new(23, 0)),
+        DayClock::new(-1, Clock::new(12, 0)),
+        DayClock::from(Clock::new(2, 0)),
+    ];
+    timeline.sort();
+    let days: Vec<String> = timeline.iter().map(DayClock::to_string).collect();
+    assert_eq!(days, ["day -1 12:00", "day 0 02:00", "day 0 23:00", "day 1 01:00"]);
+}
+
+#[test]
+fn test_day_clock_total_minutes() {
+    assert_eq!(DayClock::new(2, Clock::new(1, 30)).total_minutes(), 2 * 1440 + 90);
+    assert_eq!(DayClock::new(-1, Clock::new(23, 0)).total_minutes(), -60);
+}
@@ -0,0 +1,93 @@
+use clock::{Clock, ClockDuration, DurationError};
+use std::convert::TryFrom;
+use std::time::Duration;
+
+#[test]
+fn test_forward_distance_across_midnight() {
+    assert_eq!(Clock::new(1, 5) - Clock::new(23, 10), ClockDuration::from_minutes(115));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_forward_distance_is_never_negative() {
+    assert_eq!(Clock::new(23, 10) - Clock::new(1, 5), ClockDuration::from_minutes(1325));
+    assert_eq!(&Clock::new(8, 0) - &Clock::new(8, 0), ClockDuration::ZERO);
+}
+
+#[test]
+fn test_shortest_distance_forward() {
+    assert_eq!(Clock::new(23, 10).shortest_distance(&Clock::new(1, 5)), ClockDuration::from_minutes(115));
+}
+
+#[test]
+fn test_shortest_distance_backward() {
+    assert_eq!(Clock::new(1, 5).shortest_distance(&Clock::new(23, 10)), ClockDuration::from_minutes(-115));
+}
+
+#[test]
+fn test_shortest_distance_of_opposite_clocks() {
+    assert_eq!(Clock::new(3, 0).shortest_distance(&Clock::new(15, 0)), ClockDuration::from_minutes(720));
+    assert_eq!(Clock::new(15, 0).shortest_distance(&Clock::new(3, 0)), ClockDuration::from_minutes(720));
+}
+
+#[test]
+fn test_accessors_round_towards_zero() {
+    let duration = ClockDuration::from_seconds(-150);
+    assert_eq!(duration.minutes(), -2);
+    assert_eq!(duration.seconds(), -150);
+    assert!(duration.is_negative());
+    assert_eq!(duration.abs(), ClockDuration::from_seconds(150));
+    assert_eq!(-duration, ClockDuration::from_seconds(150));
+}
+
+#[test]
+fn test_negation_of_the_most_negative_duration() {
+    let min = ClockDuration::from_nanos(i64::MIN);
+    assert_eq!(min.checked_abs(), None);
+    assert_eq!(min.checked_neg(), None);
+    let max = ClockDuration::from_nanos(i64::MAX);
+    assert_eq!(max.checked_neg(), Some(ClockDuration::from_nanos(-i64::MAX)));
+    assert_eq!((-max).checked_abs(), Some(max));
+}
+
+#[test]
+#[should_panic(expected = "ClockDuration overflow")]
+fn test_abs_overflow() {
+    ClockDuration::from_nanos(i64::MIN).abs();
+}
+
+#[test]
+#[should_panic(expected = "ClockDuration overflow")]
+fn test_neg_overflow() {
+    let _ = -ClockDuration::from_nanos(i64::MIN);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(ClockDuration::from_minutes(115).to_string(), "1h55m");
+    assert_eq!(ClockDuration::from_minutes(-5).to_string(), "-5m");
+    assert_eq!(ClockDuration::from_seconds(90).to_string(), "1m30s");
+    assert_eq!(ClockDuration::from_minutes(120).to_string(), "2h");
+    assert_eq!(ClockDuration::ZERO.to_string(), "0m");
+    let precise = ClockDuration::try_from(Duration::from_millis(1500)).unwrap();
+    assert_eq!(precise.to_string(), "1.5s");
+}
+
+#[test]
+fn test_into_std_duration() {
+    assert_eq!(Duration::try_from(ClockDuration::from_minutes(2)), Ok(Duration::from_secs(120)));
+    assert_eq!(Duration::try_from(ClockDuration::from_minutes(-2)), Err(DurationError::Negative));
+}
+
+#[test]
+fn test_from_std_duration() {
+    assert_eq!(ClockDuration::try_from(Duration::from_secs(90)), Ok(ClockDuration::from_seconds(90)));
+    assert_eq!(ClockDuration::try_from(Duration::from_secs(u64::MAX)), Err(DurationError::OutOfRange));
+}
+
+#[test]
+fn test_std_duration_round_trip() {
+    let original = Duration::new(4000, 123_456_789);
+    let duration = ClockDuration::try_from(original).unwrap();
+    assert_eq!(Duration::try_from(duration), Ok(original));
+}
@@ -0,0 +1,106 @@
+use clock::encoding::{Bcd, Binary, DecodeError, Renderer, WordClock, WordMask};
+use clock::Clock;
+
+fn all_clocks() -> impl Iterator<Item = Clock> {
+    (0..1440).map(Clock::from_minutes_since_midnight)
+}
+
+fn assert_round_trip<R: Renderer>(renderer: R) {
+    for clock in all_clocks() {
+        assert_eq!(renderer.decode(&renderer.render(clock)), Ok(clock), "{}", clock);
+    }
+}
+
+#[test]
+fn test_round_trips() {
+    assert_round_trip(Binary);
+    assert_round_trip(Bcd);
+    assert_round_trip(WordClock);
+    #[cfg(feature = "std")]
+    assert_round_trip(clock::encoding::Roman);
+}
+
+#[test]
+fn test_binary() {
+    let (o, x) = (false, true);
+    assert_eq!(Binary.render(Clock::new(13, 45)), [[o, o, x, x, o, x], [x, o, x, x, o, x]]);
+    assert_eq!(Binary.render(Clock::MIDNIGHT), [[o; 6]; 2]);
+    assert_eq!(Binary.decode(&[[o, x, x, o, o, o], [o; 6]]), Err(DecodeError::OutOfRange));
+    assert_eq!(Binary.decode(&[[o; 6], [x; 6]]), Err(DecodeError::OutOfRange));
+}
+
+#[test]
+fn test_bcd() {
+    assert_eq!(Bcd.render(Clock::new(13, 45)), [0x13, 0x45]);
+    assert_eq!(Bcd.render(Clock::new(8, 5)), [0x08, 0x05]);
+    assert_eq!(Bcd.decode(&[0x23, 0x59]), Ok(Clock::new(23, 59)));
+    assert_eq!(Bcd.decode(&[0x0a, 0x00]), Err(DecodeError::Malformed));
+    assert_eq!(Bcd.decode(&[0x12, 0xa0]), Err(DecodeError::Malformed));
+    assert_eq!(Bcd.decode(&[0x24, 0x00]), Err(DecodeError::OutOfRange));
+    assert_eq!(Bcd.decode(&[0x12, 0x60]), Err(DecodeError::OutOfRange));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_roman() {
+    use clock::encoding::Roman;
+    assert_eq!(Roman.render(Clock::new(13, 45)), "XIII:XLV");
+    assert_eq!(Roman.render(Clock::new(0, 5)), "N:V");
+    assert_eq!(Roman.render(Clock::new(19, 49)), "XIX:XLIX");
+    assert_eq!(Roman.decode(&"XXIII:LIX".to_string()), Ok(Clock::new(23, 59)));
+    for malformed in &["IIII:N", "VX:N", "N:", "XII", "XII:XX:X", "xii:N", "NN:N", "IN:N", ""] {
+        assert_eq!(Roman.decode(&malformed.to_string()), Err(DecodeError::Malformed), "{}", malformed);
+    }
+    assert_eq!(Roman.decode(&"XXIV:N".to_string()), Err(DecodeError::OutOfRange));
+    assert_eq!(Roman.decode(&"N:LX".to_string()), Err(DecodeError::OutOfRange));
+}
+
+#[test]
+fn test_word_clock() {
+    let expected = "\
+IT.IS....PM
+...........
+TWENTYFIVE.
+...........
+PAST.......
+...........
+...........
+EIGHT......
+...........
+...........
+**..";
+    assert_eq!(WordClock.render(Clock::new(20, 27)).to_string(), expected);
+}
+
+#[test]
+fn test_word_clock_counts_to_the_next_hour() {
+    let expected = "\
+IT.IS..AM..
+A.QUARTER..
+...........
+.........TO
+...........
+...........
+...........
+...........
+.....TWELVE
+...........
+....";
+    assert_eq!(WordClock.render(Clock::new(11, 45)).to_string(), expected);
+    assert_eq!(WordClock.decode(&WordClock.render(Clock::new(23, 55))), Ok(Clock::new(23, 55)));
+    assert_eq!(WordClock.decode(&WordClock.render(Clock::new(12, 40))), Ok(Clock::new(12, 40)));
+}
+
+#[test]
+fn test_word_clock_rejects_invalid_masks() {
+    assert_eq!(WordClock.decode(&WordMask::default()), Err(DecodeError::Malformed));
+    let mut mask = WordClock.render(Clock::new(8, 0));
+    mask.cells[5][0] = true;
+    assert_eq!(WordClock.decode(&mask), Err(DecodeError::Malformed));
+    let mut mask = WordClock.render(Clock::new(8, 0));
+    mask.dots = 5;
+    assert_eq!(WordClock.decode(&mask), Err(DecodeError::OutOfRange));
+    let mut mask = WordClock.render(Clock::new(8, 10));
+    mask.cells[1][0] = true;
+    assert_eq!(WordClock.decode(&mask), Err(DecodeError::Malformed));
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "std")]
+
+use clock::face::{angle_between, AsciiFace, HandAngles, SvgFace};
+use clock::{Clock, PreciseClock};
+
+#[test]
+fn test_hand_angles_on_the_hour() {
+    let angles = Clock::new(15, 0).hand_angles();
+    assert_eq!(angles, HandAngles { hour: 90.0, minute: 0.0, second: 0.0 });
+    assert!((angles.between_hour_and_minute() - 90.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_hour_hand_sweeps() {
+    let angles = Clock::new(8, 30).hand_angles();
+    assert!((angles.hour - 255.0).abs() < 1e-9);
+    assert!((angles.minute - 180.0).abs() < 1e-9);
+    assert!((angles.between_hour_and_minute() - 75.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_second_hand() {
+    let angles = PreciseClock::with_nanos(12, 0, 30, 500_000_000).hand_angles();
+    assert!((angles.second - 183.0).abs() < 1e-9);
+    assert!((angles.minute - 3.05).abs() < 1e-9);
+    assert!((angles.hour - (0.25 + 0.25 / 60.0)).abs() < 1e-9);
+    assert!((angles.between_minute_and_second() - 179.95).abs() < 1e-9);
+}
+
+#[test]
+fn test_angle_between_takes_the_shorter_way() {
+    assert!((Clock::new(12, 30).hand_angles().between_hour_and_minute() - 165.0).abs() < 1e-9);
+    assert!((Clock::new(9, 45).hand_angles().between_hour_and_minute() - 22.5).abs() < 1e-9);
+    assert!((angle_between(350.0, 10.0) - 20.0).abs() < 1e-9);
+    assert!((angle_between(10.0, 350.0) - 20.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_ascii_three_oclock() {
+    let expected = concat!(
+        "      ...12....\n",
+        "   ...    m    ...\n",
+        " ...      m      ...\n",
+        "..        m        ..\n",
+        "..        m        ..\n",
+        "9         ohhhhh    3\n",
+        "..                 ..\n",
+        "..                 ..\n",
+        " ...             ...\n",
+        "   ...         ...\n",
+        "      ....6....\n",
+    );
+    assert_eq!(AsciiFace::new(5).render(Clock::new(3, 0)), expected);
+}
+
+#[test]
+fn test_ascii_twenty_past_eight() {
+    let expected = concat!(
+        "      ...12....\n",
+        "   ...         ...\n",
+        " ...             ...\n",
+        "..                 ..\n",
+        "..                 ..\n",
+        "9       hhom        3\n",
+        "..   hhh    mmmm   ..\n",
+        "..              mm ..\n",
+        " ...             ...\n",
+        "   ...         ...\n",
+        "      ....6....\n",
+    );
+    assert_eq!(AsciiFace::new(5).render(Clock::new(20, 20)), expected);
+}
+
+#[test]
+fn test_svg_face() {
+    let expected = concat!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100" viewBox="0 0 100 100">"#, "\n",
+        r#"  <circle cx="50" cy="50" r="47.5" fill="white" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="50" y1="9.63" x2="50" y2="2.5" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="70.19" y1="15.03" x2="73.75" y2="8.86" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="84.97" y1="29.81" x2="91.14" y2="26.25" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="90.38" y1="50" x2="97.5" y2="50" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="84.97" y1="70.19" x2="91.14" y2="73.75" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="70.19" y1="84.97" x2="73.75" y2="91.14" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="50" y1="90.38" x2="50" y2="97.5" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="29.81" y1="84.97" x2="26.25" y2="91.14" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="15.03" y1="70.19" x2="8.86" y2="73.75" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="9.63" y1="50" x2="2.5" y2="50" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="15.03" y1="29.81" x2="8.86" y2="26.25" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line x1="29.81" y1="15.03" x2="26.25" y2="8.86" stroke="black" stroke-width="2"/>"#, "\n",
+        r#"  <line class="hour" x1="50" y1="50" x2="73.75" y2="50" stroke="black" stroke-width="6" stroke-linecap="round"/>"#, "\n",
+        r#"  <line class="minute" x1="50" y1="50" x2="50" y2="12" stroke="black" stroke-width="4" stroke-linecap="round"/>"#, "\n",
+        r#"  <circle cx="50" cy="50" r="3" fill="black"/>"#, "\n",
+        "</svg>\n",
+    );
+    assert_eq!(SvgFace::new(100).render(Clock::new(3, 0)), expected);
+}
+
+#[test]
+fn test_svg_second_hand() {
+    let svg = SvgFace::new(100).with_seconds(true).render(PreciseClock::new(3, 0, 45));
+    let second = r#"<line class="second" x1="50" y1="50" x2="7.25" y2="50" stroke="red""#;
+    assert!(svg.contains(second), "{}", svg);
+    assert!(!SvgFace::new(100).render(PreciseClock::new(3, 0, 45)).contains("second"));
+}
+
+#[test]
+fn test_rendering_is_deterministic() {
+    let clock = PreciseClock::with_nanos(10, 10, 10, 123_456_789);
+    assert_eq!(SvgFace::new(240).render(clock), SvgFace::new(240).render(clock));
+    assert_eq!(AsciiFace::new(7).render(clock), AsciiFace::new(7).render(clock));
+}
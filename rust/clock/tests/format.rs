@@ -0,0 +1,64 @@
+#![cfg(feature = "std")]
+
+use clock::{Clock, ClockFormat, PreciseClock};
+
+#[test]
+fn test_twelve_hour_format() {
+    assert_eq!(Clock::new(8, 5).format("%I:%M %p"), Ok("08:05 AM".to_string()));
+    assert_eq!(Clock::new(20, 5).format("%-I:%M%P"), Ok("8:05pm".to_string()));
+    assert_eq!(Clock::new(0, 0).format("%l:%M %p"), Ok("12:00 AM".to_string()));
+    assert_eq!(Clock::new(12, 30).format("%I:%M %p"), Ok("12:30 PM".to_string()));
+}
+
+#[test]
+fn test_twenty_four_hour_format() {
+    assert_eq!(Clock::new(8, 5).format("%H:%M"), Ok("08:05".to_string()));
+    assert_eq!(Clock::new(8, 5).format("%k.%M"), Ok(" 8.05".to_string()));
+    assert_eq!(Clock::new(8, 5).format("%-H h %M"), Ok("8 h 05".to_string()));
+    assert_eq!(Clock::new(8, 5).format("%R"), Ok("08:05".to_string()));
+    assert_eq!(Clock::new(8, 5).format("%_M"), Ok(" 5".to_string()));
+}
+
+#[test]
+fn test_seconds_and_fraction() {
+    let clock = PreciseClock::with_nanos(8, 5, 9, 250_000_000);
+    assert_eq!(clock.format("%T"), Ok("08:05:09".to_string()));
+    assert_eq!(clock.format("%S.%3f"), Ok("09.250".to_string()));
+    assert_eq!(clock.format("%6f|%f"), Ok("250000|250000000".to_string()));
+}
+
+#[test]
+fn test_literals() {
+    assert_eq!(Clock::new(8, 5).format("100%% at %Hh"), Ok("100% at 08h".to_string()));
+    assert_eq!(Clock::new(8, 5).format("время: %H·%M"), Ok("время: 08·05".to_string()));
+    assert_eq!(Clock::new(8, 5).format(""), Ok(String::new()));
+}
+
+#[test]
+fn test_invalid_specifiers() {
+    assert_eq!(Clock::new(8, 5).format("%H:%Q").unwrap_err().position(), 3);
+    assert_eq!(Clock::new(8, 5).format("%H%").unwrap_err().position(), 2);
+    assert_eq!(Clock::new(8, 5).format("%-p").unwrap_err().position(), 0);
+    assert_eq!(Clock::new(8, 5).format("%3S").unwrap_err().position(), 0);
+    assert_eq!(
+        "ab%y".parse::<ClockFormat>().unwrap_err().to_string(),
+        "invalid format specifier at position 2"
+    );
+}
+
+#[test]
+fn test_reusable_format() {
+    let format = ClockFormat::new("%-I:%M %p").unwrap();
+    let clocks = [Clock::new(9, 0), Clock::new(13, 45), Clock::new(23, 59)];
+    let formatted: Vec<String> = clocks.iter().map(|clock| format.format(clock).to_string()).collect();
+    assert_eq!(formatted, ["9:00 AM", "1:45 PM", "11:59 PM"]);
+}
+
+#[test]
+fn test_alternate_display() {
+    assert_eq!(format!("{:#}", Clock::new(8, 5)), "8:05 AM");
+    assert_eq!(format!("{:#}", Clock::new(0, 30)), "12:30 AM");
+    assert_eq!(format!("{:#}", Clock::new(12, 0)), "12:00 PM");
+    assert_eq!(format!("{:#}", Clock::new(23, 59)), "11:59 PM");
+    assert_eq!(format!("{}", Clock::new(23, 59)), "23:59");
+}
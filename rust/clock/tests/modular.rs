@@ -0,0 +1,109 @@
+use clock::{Clock, ClockDuration, Dial, DialMismatch, ModularClock};
+use std::convert::TryFrom;
+
+#[test]
+fn test_clock_is_a_standard_modular_clock() {
+    assert_eq!(Clock::DIAL, Dial::STANDARD);
+    let modular = ModularClock::from(Clock::new(8, 30));
+    assert_eq!(modular, ModularClock::new(Dial::STANDARD, 8, 30));
+    assert_eq!(modular.to_string(), "08:30");
+    assert_eq!(modular.to_clock(), Clock::new(8, 30));
+    assert_eq!(modular.second(), None);
+}
+
+#[test]
+fn test_every_clock_round_trips() {
+    for minutes in 0..24 * 60 {
+        let clock = Clock::from_minutes_since_midnight(minutes);
+        let modular = ModularClock::from(clock);
+        assert_eq!(modular.dial(), Clock::DIAL);
+        assert_eq!(Clock::try_from(modular), Ok(clock));
+    }
+}
+
+#[test]
+fn test_clock_only_from_the_standard_dial() {
+    let decimal = ModularClock::new(Dial::DECIMAL, 5, 0);
+    assert_eq!(Clock::try_from(decimal), Err(DialMismatch { dial: Dial::DECIMAL }));
+    assert_eq!(decimal.to_clock(), Clock::new(12, 0));
+    let slow = Dial::STANDARD.with_day_length(ClockDuration::from_minutes(25 * 60));
+    assert!(Clock::try_from(ModularClock::new(slow, 8, 30)).is_err());
+}
+
+#[test]
+fn test_standard_arithmetic_matches_clock() {
+    for &(hours, minutes) in &[(25, 160), (-1, -1), (-121, -5810), (0, 1_000_000)] {
+        let modular = ModularClock::new(Dial::STANDARD, hours.into(), minutes.into());
+        assert_eq!(modular.to_clock(), Clock::new(hours, minutes));
+    }
+}
+
+#[test]
+fn test_twenty_hour_day() {
+    let dial = Dial::new(20, 60);
+    assert_eq!(ModularClock::new(dial, 19, 59).add_minutes(2).to_string(), "00:01");
+    assert_eq!(ModularClock::new(dial, 20, 0), ModularClock::new(dial, 0, 0));
+    assert_eq!(ModularClock::new(dial, 0, -1).to_string(), "19:59");
+    assert_eq!(ModularClock::new(dial, 10, 0).to_clock(), Clock::new(12, 0));
+}
+
+#[test]
+fn test_decimal_time() {
+    let clock = ModularClock::with_seconds(Dial::DECIMAL, 5, 0, 0);
+    assert_eq!(clock.to_string(), "5:00:00");
+    assert_eq!(clock.to_clock(), Clock::NOON);
+    assert_eq!(ModularClock::from(Clock::new(18, 0)).to_dial(Dial::DECIMAL).to_string(), "7:50:00");
+    assert_eq!(ModularClock::with_seconds(Dial::DECIMAL, 9, 99, 100).to_string(), "0:00:00");
+    let second = ModularClock::with_seconds(Dial::DECIMAL, 0, 0, 1);
+    assert_eq!(second.since_midnight(), ClockDuration::from_nanos(864_000_000));
+}
+
+#[test]
+fn test_mars_time() {
+    let sol = Dial::MARS.day_length();
+    assert_eq!(sol, ClockDuration::from_nanos(88_775_244_000_000));
+    let noon = ModularClock::with_seconds(Dial::MARS, 12, 0, 0);
+    assert_eq!(noon.since_midnight(), ClockDuration::from_nanos(44_387_622_000_000));
+    // An Earth day is a little less than a sol.
+    let midnight = ModularClock::with_seconds(Dial::MARS, 0, 0, 0);
+    assert_eq!(midnight.add_duration(ClockDuration::from_minutes(24 * 60)).to_string(), "23:21:28");
+}
+
+#[test]
+fn test_add_duration_on_standard_dial() {
+    let clock = ModularClock::new(Dial::STANDARD, 23, 0);
+    assert_eq!(clock.add_duration(ClockDuration::from_seconds(90 * 60 + 59)).to_clock(), Clock::new(0, 30));
+    assert_eq!(clock.add_duration(-ClockDuration::from_minutes(1)).to_clock(), Clock::new(22, 59));
+}
+
+#[test]
+fn test_accessors() {
+    let clock = ModularClock::with_seconds(Dial::DECIMAL, 3, 14, 15);
+    assert_eq!((clock.hour(), clock.minute(), clock.second()), (3, 14, Some(15)));
+    assert_eq!(clock.dial().hours_per_day(), 10);
+    assert_eq!(Dial::DECIMAL.seconds_per_minute(), Some(100));
+}
+
+#[test]
+#[should_panic]
+fn test_seconds_on_dial_without_seconds() {
+    ModularClock::with_seconds(Dial::STANDARD, 0, 0, 1);
+}
+
+#[test]
+#[should_panic]
+fn test_empty_dial() {
+    Dial::new(0, 60);
+}
+
+#[test]
+#[should_panic]
+fn test_dial_with_too_many_seconds() {
+    Dial::new(65_536, 65_535).with_seconds(u32::MAX);
+}
+
+#[test]
+fn test_largest_dial() {
+    let dial = Dial::new(1 << 16, 1 << 8).with_seconds(255);
+    assert_eq!(ModularClock::with_seconds(dial, -1, 0, 0).to_string(), "65535:000:000");
+}
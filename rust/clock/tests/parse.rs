@@ -0,0 +1,97 @@
+use clock::{Clock, ParseClockError, ParseErrorKind, PreciseClock};
+use std::convert::TryFrom;
+
+fn error(input: &str) -> (usize, ParseErrorKind) {
+    let err: ParseClockError = input.parse::<PreciseClock>().unwrap_err();
+    (err.position(), err.kind())
+}
+
+#[test]
+fn test_hours_and_minutes() {
+    assert_eq!("08:30".parse(), Ok(Clock::new(8, 30)));
+    assert_eq!("8:30".parse(), Ok(Clock::new(8, 30)));
+    assert_eq!(" 23:59 ".parse(), Ok(Clock::new(23, 59)));
+}
+
+#[test]
+fn test_with_seconds() {
+    assert_eq!("08:30:15".parse(), Ok(PreciseClock::new(8, 30, 15)));
+    assert_eq!("08:30:00".parse(), Ok(Clock::new(8, 30)));
+}
+
+#[test]
+fn test_with_fraction() {
+    assert_eq!("08:30:15.250".parse(), Ok(PreciseClock::with_nanos(8, 30, 15, 250_000_000)));
+    assert_eq!("08:30:15,000000001".parse(), Ok(PreciseClock::with_nanos(8, 30, 15, 1)));
+}
+
+#[test]
+fn test_twelve_hour_format() {
+    assert_eq!("8:30 am".parse(), Ok(Clock::new(8, 30)));
+    assert_eq!("8:30pm".parse(), Ok(Clock::new(20, 30)));
+    assert_eq!("12:05 AM".parse(), Ok(Clock::new(0, 5)));
+    assert_eq!("12:05 P.M.".parse(), Ok(Clock::new(12, 5)));
+}
+
+#[test]
+fn test_military_format() {
+    assert_eq!("0830".parse(), Ok(Clock::new(8, 30)));
+    assert_eq!("2359".parse(), Ok(Clock::new(23, 59)));
+}
+
+#[test]
+fn test_iso_8601() {
+    assert_eq!("T08:30:15Z".parse(), Ok(PreciseClock::new(8, 30, 15)));
+    assert_eq!("083015.5+05:30".parse(), Ok(PreciseClock::with_nanos(8, 30, 15, 500_000_000)));
+    assert_eq!("08:30-0800".parse(), Ok(Clock::new(8, 30)));
+    assert_eq!("08:30+01".parse(), Ok(Clock::new(8, 30)));
+    assert_eq!("24:00".parse(), Ok(Clock::new(0, 0)));
+}
+
+#[test]
+fn test_try_from() {
+    assert_eq!(Clock::try_from("10:15"), Ok(Clock::new(10, 15)));
+    assert_eq!(PreciseClock::try_from("10:15:01"), Ok(PreciseClock::new(10, 15, 1)));
+}
+
+#[test]
+fn test_empty_input() {
+    assert_eq!(error("  "), (2, ParseErrorKind::Empty));
+}
+
+#[test]
+fn test_out_of_range_components() {
+    assert_eq!(error("25:00"), (0, ParseErrorKind::HourOutOfRange));
+    assert_eq!(error("24:01"), (0, ParseErrorKind::HourOutOfRange));
+    assert_eq!(error("10:60"), (3, ParseErrorKind::MinuteOutOfRange));
+    assert_eq!(error("10:00:61"), (6, ParseErrorKind::SecondOutOfRange));
+    assert_eq!(error("13:00 pm"), (0, ParseErrorKind::HourOutOfRange));
+    assert_eq!(error("0:00 am"), (0, ParseErrorKind::HourOutOfRange));
+    assert_eq!(error("10:00+24:00"), (5, ParseErrorKind::OffsetOutOfRange));
+}
+
+#[test]
+fn test_unexpected_characters() {
+    assert_eq!(error("10h30"), (2, ParseErrorKind::UnexpectedChar('h')));
+    assert_eq!(error("10:3"), (4, ParseErrorKind::UnexpectedEnd));
+    assert_eq!(error("10:30 tomorrow"), (6, ParseErrorKind::UnexpectedChar('t')));
+    assert_eq!(error("08301"), (4, ParseErrorKind::UnexpectedChar('1')));
+    assert_eq!(error(":30"), (0, ParseErrorKind::UnexpectedChar(':')));
+    assert_eq!(error("10:30:15."), (9, ParseErrorKind::UnexpectedEnd));
+}
+
+#[test]
+fn test_clock_rejects_seconds() {
+    let err = "08:30:15".parse::<Clock>().unwrap_err();
+    assert_eq!((err.position(), err.kind()), (6, ParseErrorKind::TooPrecise));
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(error_message("25:00"), "hour out of range at position 0");
+    assert_eq!(error_message("1x:00"), "unexpected character 'x' at position 1");
+}
+
+fn error_message(input: &str) -> String {
+    input.parse::<Clock>().unwrap_err().to_string()
+}
@@ -0,0 +1,81 @@
+use clock::{Clock, ClockDuration, PreciseClock};
+
+#[test]
+fn test_display_whole_seconds() {
+    assert_eq!(PreciseClock::new(8, 30, 15).to_string(), "08:30:15");
+}
+
+#[test]
+fn test_display_milliseconds() {
+    assert_eq!(PreciseClock::with_nanos(8, 30, 15, 250_000_000).to_string(), "08:30:15.250");
+}
+
+#[test]
+fn test_display_finer_fractions() {
+    assert_eq!(PreciseClock::with_nanos(8, 30, 15, 250_500).to_string(), "08:30:15.000250500");
+    assert_eq!(PreciseClock::with_nanos(8, 30, 15, 1_000).to_string(), "08:30:15.000001");
+}
+
+#[test]
+fn test_display_with_precision() {
+    let clock = PreciseClock::with_nanos(8, 30, 15, 259_000_000);
+    assert_eq!(format!("{:.1}", clock), "08:30:15.2");
+    assert_eq!(format!("{:.0}", clock), "08:30:15");
+    assert_eq!(format!("{:.6}", PreciseClock::new(8, 30, 15)), "08:30:15.000000");
+}
+
+#[test]
+fn test_seconds_roll_over() {
+    assert_eq!(PreciseClock::new(23, 59, 60).to_string(), "00:00:00");
+    assert_eq!(PreciseClock::new(0, 0, 3725).to_string(), "01:02:05");
+}
+
+#[test]
+fn test_negative_seconds_and_nanos_roll_over() {
+    assert_eq!(PreciseClock::new(0, 0, -1).to_string(), "23:59:59");
+    assert_eq!(PreciseClock::with_nanos(0, 0, 0, -1).to_string(), "23:59:59.999999999");
+}
+
+#[test]
+fn test_accessors() {
+    let clock = PreciseClock::with_nanos(25, -1, 61, 1_500_000_000);
+    assert_eq!((clock.hour(), clock.minute(), clock.second(), clock.nanosecond()), (1, 0, 2, 500_000_000));
+}
+
+#[test]
+fn test_add_seconds_across_midnight() {
+    assert_eq!(PreciseClock::new(23, 59, 30).add_seconds(45).to_string(), "00:00:15");
+    assert_eq!(PreciseClock::new(0, 0, 15).add_seconds(-45).to_string(), "23:59:30");
+    assert_eq!(PreciseClock::new(0, 0, 0).add_seconds(i64::MIN), PreciseClock::new(0, 0, 0).add_seconds(i64::MIN % 86400));
+}
+
+#[test]
+fn test_duration_operators() {
+    let mut clock = PreciseClock::new(12, 0, 0);
+    clock += ClockDuration::from_seconds(90);
+    assert_eq!(clock, PreciseClock::new(12, 1, 30));
+    clock -= ClockDuration::from_nanos(1);
+    assert_eq!(clock.to_string(), "12:01:29.999999999");
+    assert_eq!(clock - ClockDuration::from_minutes(1441), PreciseClock::with_nanos(12, 0, 29, 999_999_999));
+}
+
+#[test]
+fn test_difference() {
+    let later = PreciseClock::with_nanos(0, 0, 1, 500_000_000);
+    let earlier = PreciseClock::new(23, 59, 59);
+    assert_eq!(later - earlier, ClockDuration::from_nanos(2_500_000_000));
+    assert_eq!(later.shortest_distance(&earlier), ClockDuration::from_nanos(-2_500_000_000));
+}
+
+#[test]
+fn test_conversion_to_and_from_clock() {
+    let precise = PreciseClock::from(Clock::new(8, 30));
+    assert_eq!(precise, PreciseClock::new(8, 30, 0));
+    assert_eq!(PreciseClock::new(8, 30, 59).to_clock(), Clock::new(8, 30));
+}
+
+#[test]
+fn test_clock_plus_duration_rounds_down() {
+    assert_eq!(Clock::new(10, 0) + ClockDuration::from_seconds(90), Clock::new(10, 1));
+    assert_eq!(Clock::new(10, 0) - ClockDuration::from_seconds(30), Clock::new(9, 59));
+}
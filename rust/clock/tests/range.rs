@@ -0,0 +1,120 @@
+use clock::{clock, Clock, ClockDuration, ClockRange};
+
+#[test]
+fn test_length() {
+    assert_eq!(ClockRange::new(clock!(09:00), clock!(17:30)).len(), ClockDuration::from_minutes(510));
+    assert_eq!(ClockRange::new(clock!(22:00), clock!(06:00)).len(), ClockDuration::from_minutes(480));
+    assert!(ClockRange::new(clock!(06:00), clock!(06:00)).is_empty());
+    assert_eq!(ClockRange::full_day(Clock::new(6, 0)).len(), ClockDuration::from_minutes(1440));
+}
+
+#[test]
+fn test_contains() {
+    let night = ClockRange::new(clock!(22:00), clock!(06:00));
+    assert!(night.contains(&Clock::new(22, 0)));
+    assert!(night.contains(&Clock::new(0, 0)));
+    assert!(night.contains(&Clock::new(5, 59)));
+    assert!(!night.contains(&Clock::new(6, 0)));
+    assert!(!night.contains(&Clock::new(21, 59)));
+    assert!(!ClockRange::new(clock!(06:00), clock!(06:00)).contains(&Clock::new(6, 0)));
+    assert!(ClockRange::full_day(Clock::new(6, 0)).contains(&Clock::new(5, 59)));
+}
+
+#[test]
+fn test_wraps() {
+    assert!(ClockRange::new(clock!(22:00), clock!(06:00)).wraps());
+    assert!(!ClockRange::new(clock!(22:00), clock!(00:00)).wraps());
+    assert!(!ClockRange::new(clock!(00:00), clock!(06:00)).wraps());
+}
+
+#[test]
+fn test_overlaps() {
+    let night = ClockRange::new(clock!(22:00), clock!(06:00));
+    assert!(night.overlaps(&ClockRange::new(clock!(05:00), clock!(07:00))));
+    assert!(night.overlaps(&ClockRange::new(clock!(23:00), clock!(01:00))));
+    assert!(!night.overlaps(&ClockRange::new(clock!(06:00), clock!(22:00))));
+    assert!(!night.overlaps(&ClockRange::new(clock!(12:00), clock!(12:00))));
+}
+
+#[test]
+fn test_intersection() {
+    let office = ClockRange::new(clock!(09:00), clock!(17:00));
+    let night = ClockRange::new(clock!(22:00), clock!(06:00));
+    let afternoon = ClockRange::new(clock!(12:00), clock!(20:00));
+    assert_eq!(office.intersection(&afternoon), [ClockRange::new(clock!(12:00), clock!(17:00))]);
+    let late = ClockRange::new(clock!(23:00), clock!(07:00));
+    assert_eq!(night.intersection(&late), [ClockRange::new(clock!(23:00), clock!(06:00))]);
+    assert_eq!(night.intersection(&office), []);
+}
+
+#[test]
+fn test_intersection_in_two_parts() {
+    let night = ClockRange::new(clock!(20:00), clock!(08:00));
+    let day = ClockRange::new(clock!(06:00), clock!(22:00));
+    assert_eq!(
+        night.intersection(&day),
+        [ClockRange::new(clock!(06:00), clock!(08:00)), ClockRange::new(clock!(20:00), clock!(22:00))]
+    );
+}
+
+#[test]
+fn test_ranges_as_slice() {
+    let night = ClockRange::new(clock!(20:00), clock!(08:00));
+    let ranges = night.intersection(&ClockRange::new(clock!(06:00), clock!(22:00)));
+    assert_eq!(ranges.len(), 2);
+    assert_eq!(ranges[1], ClockRange::new(clock!(20:00), clock!(22:00)));
+    let lengths: Vec<ClockDuration> = ranges.iter().map(ClockRange::len).collect();
+    assert_eq!(lengths, [ClockDuration::from_minutes(120); 2]);
+    let morning = ClockRange::new(clock!(09:00), clock!(12:00));
+    assert!(morning.intersection(&ClockRange::new(clock!(13:00), clock!(14:00))).is_empty());
+}
+
+#[test]
+fn test_intersection_with_full_day() {
+    let full = ClockRange::full_day(Clock::new(12, 0));
+    let night = ClockRange::new(clock!(22:00), clock!(06:00));
+    assert_eq!(full.intersection(&night), [night]);
+    assert_eq!(full.intersection(&full), [ClockRange::full_day(Clock::new(12, 0))]);
+}
+
+#[test]
+fn test_union() {
+    let morning = ClockRange::new(clock!(09:00), clock!(12:00));
+    let nine_to_two = ClockRange::new(clock!(09:00), clock!(14:00));
+    assert_eq!(morning.union(&ClockRange::new(clock!(11:00), clock!(14:00))), [nine_to_two]);
+    assert_eq!(morning.union(&ClockRange::new(clock!(12:00), clock!(14:00))), [nine_to_two]);
+    let lunch = ClockRange::new(clock!(13:00), clock!(14:00));
+    assert_eq!(morning.union(&lunch), [morning, lunch]);
+    assert_eq!(morning.union(&ClockRange::new(clock!(01:00), clock!(01:00))), [morning]);
+}
+
+#[test]
+fn test_union_across_midnight() {
+    let night = ClockRange::new(clock!(22:00), clock!(06:00));
+    let late = ClockRange::new(clock!(22:00), clock!(02:00));
+    assert_eq!(late.union(&ClockRange::new(clock!(01:00), clock!(06:00))), [night]);
+    let evening = ClockRange::new(clock!(22:00), clock!(00:00));
+    assert_eq!(evening.union(&ClockRange::new(clock!(00:00), clock!(06:00))), [night]);
+    let lunch = ClockRange::new(clock!(12:00), clock!(13:00));
+    assert_eq!(late.union(&lunch), [lunch, late]);
+}
+
+#[test]
+fn test_union_covering_the_day() {
+    let union = ClockRange::new(clock!(06:00), clock!(22:00)).union(&ClockRange::new(clock!(21:00), clock!(07:00)));
+    assert_eq!(union, [ClockRange::full_day(Clock::new(6, 0))]);
+}
+
+#[test]
+fn test_iteration() {
+    let clocks: Vec<String> = ClockRange::new(clock!(23:58), clock!(00:02)).iter().map(|c| c.to_string()).collect();
+    assert_eq!(clocks, ["23:58", "23:59", "00:00", "00:01"]);
+    assert_eq!(ClockRange::new(clock!(23:58), clock!(00:02)).iter().next_back(), Some(Clock::new(0, 1)));
+    assert_eq!(ClockRange::full_day(Clock::new(0, 0)).iter().len(), 1440);
+    assert_eq!((&ClockRange::new(clock!(05:00), clock!(05:00))).into_iter().next(), None);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(ClockRange::new(clock!(22:00), clock!(06:00)).to_string(), "22:00-06:00");
+}
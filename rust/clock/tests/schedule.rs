@@ -0,0 +1,161 @@
+#![cfg(feature = "std")]
+
+use clock::{Alarm, Clock, ClockRange, DayClock, Schedule, Weekday, Weekdays};
+
+#[test]
+fn test_weekdays() {
+    assert_eq!(Weekday::of_day(0), Weekday::Monday);
+    assert_eq!(Weekday::of_day(13), Weekday::Sunday);
+    assert_eq!(Weekday::of_day(-1), Weekday::Sunday);
+    assert!(Weekdays::WORKDAYS.contains(Weekday::Friday));
+    assert!(!Weekdays::WORKDAYS.contains(Weekday::Saturday));
+    assert_eq!(Weekday::Saturday | Weekday::Sunday, Weekdays::WEEKEND);
+    assert_eq!(Weekdays::WORKDAYS | Weekdays::WEEKEND, Weekdays::ALL);
+    assert!(Weekdays::NONE.is_empty());
+}
+
+#[test]
+fn test_next_after_daily() {
+    let schedule = Schedule::new().with(Alarm::daily(Clock::new(7, 0))).with(Alarm::daily(Clock::new(19, 0)));
+    assert_eq!(schedule.next_after(&Clock::new(6, 59)), Some(Clock::new(7, 0)));
+    assert_eq!(schedule.next_after(&Clock::new(7, 0)), Some(Clock::new(19, 0)));
+    assert_eq!(schedule.next_after(&Clock::new(20, 0)), Some(Clock::new(7, 0)));
+    assert_eq!(Schedule::new().next_after(&Clock::new(20, 0)), None);
+}
+
+#[test]
+fn test_every_within_window() {
+    let schedule = Schedule::from(Alarm::every(45, ClockRange::new(Clock::new(9, 0), Clock::new(11, 0))));
+    assert_eq!(schedule.occurrences_between(&Clock::new(0, 0), &Clock::new(0, 0)), []);
+    assert_eq!(
+        schedule.occurrences_in(&ClockRange::full_day(Clock::new(0, 0))),
+        [Clock::new(9, 0), Clock::new(9, 45), Clock::new(10, 30)]
+    );
+    assert_eq!(schedule.next_after(&Clock::new(10, 30)), Some(Clock::new(9, 0)));
+}
+
+#[test]
+fn test_every_across_midnight() {
+    let schedule = Schedule::from(Alarm::every(90, ClockRange::new(Clock::new(22, 0), Clock::new(2, 0))));
+    assert_eq!(
+        schedule.occurrences_between(&Clock::new(12, 0), &Clock::new(12, 0)),
+        []
+    );
+    assert_eq!(
+        schedule.occurrences_between(&Clock::new(21, 0), &Clock::new(3, 0)),
+        [Clock::new(22, 0), Clock::new(23, 30), Clock::new(1, 0)]
+    );
+    assert_eq!(schedule.next_after(&Clock::new(23, 30)), Some(Clock::new(1, 0)));
+}
+
+#[test]
+fn test_occurrences_between() {
+    let evening = ClockRange::new(Clock::new(20, 0), Clock::new(23, 0));
+    let schedule: Schedule = vec![Alarm::daily(Clock::new(8, 0)), Alarm::every(60, evening)].into_iter().collect();
+    assert_eq!(
+        schedule.occurrences_between(&Clock::new(21, 0), &Clock::new(8, 0)),
+        [Clock::new(21, 0), Clock::new(22, 0)]
+    );
+    assert_eq!(
+        schedule.occurrences_between(&Clock::new(21, 30), &Clock::new(8, 1)),
+        [Clock::new(22, 0), Clock::new(8, 0)]
+    );
+}
+
+#[test]
+fn test_next_after_on_weekdays() {
+    let schedule = Schedule::from(Alarm::daily(Clock::new(7, 0)).on(Weekdays::WORKDAYS));
+    // Day 4 is a Friday.
+    let friday_evening = DayClock::new(4, Clock::new(20, 0));
+    assert_eq!(schedule.next_after_day(&friday_evening), Some(DayClock::new(7, Clock::new(7, 0))));
+    let monday_morning = DayClock::new(7, Clock::new(6, 0));
+    assert_eq!(schedule.next_after_day(&monday_morning), Some(DayClock::new(7, Clock::new(7, 0))));
+}
+
+#[test]
+fn test_next_after_once_a_week() {
+    let schedule = Schedule::from(Alarm::daily(Clock::new(7, 0)).on(Weekday::Wednesday));
+    let wednesday = DayClock::new(2, Clock::new(7, 0));
+    assert_eq!(schedule.next_after_day(&wednesday), Some(DayClock::new(9, Clock::new(7, 0))));
+    let disabled = Schedule::from(Alarm::daily(Clock::new(7, 0)).on(Weekdays::NONE));
+    assert_eq!(disabled.next_after_day(&wednesday), None);
+    assert_eq!(disabled.next_after(&Clock::new(0, 0)), None);
+}
+
+#[test]
+fn test_occurrences_between_days() {
+    let schedule = Schedule::new()
+        .with(Alarm::daily(Clock::new(9, 0)).on(Weekdays::WEEKEND))
+        .with(Alarm::daily(Clock::new(6, 30)).on(Weekdays::WORKDAYS));
+    let occurrences: Vec<DayClock> = schedule
+        .occurrences_between_days(&DayClock::new(4, Clock::new(7, 0)), &DayClock::new(7, Clock::new(6, 30)))
+        .collect();
+    assert_eq!(occurrences, [DayClock::new(5, Clock::new(9, 0)), DayClock::new(6, Clock::new(9, 0))]);
+    let from_alarm: Vec<DayClock> = schedule
+        .occurrences_between_days(&DayClock::new(5, Clock::new(9, 0)), &DayClock::new(5, Clock::new(9, 1)))
+        .collect();
+    assert_eq!(from_alarm, [DayClock::new(5, Clock::new(9, 0))]);
+}
+
+#[test]
+fn test_occurrences_over_weeks() {
+    let schedule = Schedule::new()
+        .with(Alarm::daily(Clock::new(8, 0)).on(Weekday::Monday | Weekday::Thursday))
+        .with(Alarm::daily(Clock::new(20, 0)).on(Weekday::Sunday));
+    let occurrences: Vec<DayClock> = schedule
+        .occurrences_between_days(&DayClock::new(0, Clock::new(12, 0)), &DayClock::new(21, Clock::new(8, 0)))
+        .collect();
+    let days: Vec<(i64, Clock)> = occurrences.iter().map(|moment| (moment.day(), moment.clock())).collect();
+    assert_eq!(
+        days,
+        [
+            (3, Clock::new(8, 0)),
+            (6, Clock::new(20, 0)),
+            (7, Clock::new(8, 0)),
+            (10, Clock::new(8, 0)),
+            (13, Clock::new(20, 0)),
+            (14, Clock::new(8, 0)),
+            (17, Clock::new(8, 0)),
+            (20, Clock::new(20, 0)),
+        ]
+    );
+}
+
+#[test]
+fn test_occurrences_over_the_whole_timeline() {
+    let schedule = Schedule::from(Alarm::daily(Clock::new(7, 0)).on(Weekday::Monday));
+    let start = DayClock::new(i64::MIN, Clock::MIDNIGHT);
+    let end = DayClock::new(i64::MAX, Clock::new(23, 59));
+    let first: Vec<DayClock> = schedule.occurrences_between_days(&start, &end).take(3).collect();
+    assert_eq!(first.len(), 3);
+    assert!(first.iter().all(|moment| Weekday::of_day(moment.day()) == Weekday::Monday));
+    assert_eq!(first[1].day() - first[0].day(), 7);
+    let last = DayClock::new(i64::MAX - 6, Clock::MIDNIGHT);
+    assert_eq!(schedule.occurrences_between_days(&last, &end).count(), 1);
+    assert_eq!(Schedule::new().occurrences_between_days(&start, &end).next(), None);
+}
+
+#[test]
+fn test_collisions() {
+    let hourly = Schedule::from(Alarm::every(60, ClockRange::full_day(Clock::new(0, 30))));
+    let standup = Schedule::from(Alarm::daily(Clock::new(9, 30)).on(Weekdays::WORKDAYS));
+    let lunch = Schedule::from(Alarm::daily(Clock::new(12, 0)));
+    assert_eq!(hourly.collisions(&standup), [Clock::new(9, 30)]);
+    assert!(!hourly.collides_with(&lunch));
+}
+
+#[test]
+fn test_collisions_need_a_common_weekday() {
+    let weekend = Schedule::from(Alarm::daily(Clock::new(10, 0)).on(Weekdays::WEEKEND));
+    let workdays = Schedule::from(Alarm::daily(Clock::new(10, 0)).on(Weekdays::WORKDAYS));
+    let morning = ClockRange::new(Clock::new(9, 0), Clock::new(11, 0));
+    let friday = Schedule::from(Alarm::every(30, morning).on(Weekday::Friday));
+    assert!(!weekend.collides_with(&workdays));
+    assert_eq!(workdays.collisions(&friday), [Clock::new(10, 0)]);
+}
+
+#[test]
+#[should_panic]
+fn test_zero_interval() {
+    Alarm::every(0, ClockRange::new(Clock::new(9, 0), Clock::new(10, 0)));
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+use clock::spoken::{English, French, German, Russian, SpokenLanguage};
+use clock::Clock;
+
+fn say<L: SpokenLanguage>(language: L, hours: i32, minutes: i32) -> String {
+    language.speak(Clock::new(hours, minutes))
+}
+
+#[test]
+fn test_english() {
+    assert_eq!(say(English, 0, 0), "midnight");
+    assert_eq!(say(English, 12, 0), "noon");
+    assert_eq!(say(English, 8, 0), "eight o'clock");
+    assert_eq!(say(English, 20, 15), "quarter past eight");
+    assert_eq!(say(English, 8, 30), "half past eight");
+    assert_eq!(say(English, 9, 40), "twenty to ten");
+    assert_eq!(say(English, 8, 45), "quarter to nine");
+    assert_eq!(say(English, 8, 25), "twenty-five past eight");
+    assert_eq!(say(English, 8, 1), "one minute past eight");
+    assert_eq!(say(English, 8, 23), "twenty-three minutes past eight");
+    assert_eq!(say(English, 23, 50), "ten to midnight");
+    assert_eq!(say(English, 11, 59), "one minute to noon");
+}
+
+#[test]
+fn test_german() {
+    assert_eq!(say(German, 0, 0), "Mitternacht");
+    assert_eq!(say(German, 13, 0), "ein Uhr");
+    assert_eq!(say(German, 12, 0), "zwölf Uhr");
+    assert_eq!(say(German, 8, 15), "Viertel nach acht");
+    assert_eq!(say(German, 8, 30), "halb neun");
+    assert_eq!(say(German, 8, 25), "fünf vor halb neun");
+    assert_eq!(say(German, 8, 35), "fünf nach halb neun");
+    assert_eq!(say(German, 8, 45), "Viertel vor neun");
+    assert_eq!(say(German, 8, 50), "zehn vor neun");
+    assert_eq!(say(German, 0, 30), "halb eins");
+    assert_eq!(say(German, 8, 1), "eine Minute nach acht");
+    assert_eq!(say(German, 8, 37), "dreiundzwanzig Minuten vor neun");
+}
+
+#[test]
+fn test_french() {
+    assert_eq!(say(French, 0, 0), "minuit");
+    assert_eq!(say(French, 12, 0), "midi");
+    assert_eq!(say(French, 1, 0), "une heure");
+    assert_eq!(say(French, 8, 5), "huit heures cinq");
+    assert_eq!(say(French, 8, 15), "huit heures et quart");
+    assert_eq!(say(French, 8, 30), "huit heures et demie");
+    assert_eq!(say(French, 12, 30), "midi et demi");
+    assert_eq!(say(French, 8, 40), "neuf heures moins vingt");
+    assert_eq!(say(French, 11, 45), "midi moins le quart");
+    assert_eq!(say(French, 8, 21), "huit heures vingt et une");
+    assert_eq!(say(French, 8, 37), "huit heures trente-sept");
+    assert_eq!(say(French, 23, 55), "minuit moins cinq");
+}
+
+#[test]
+fn test_russian() {
+    assert_eq!(say(Russian, 0, 0), "полночь");
+    assert_eq!(say(Russian, 12, 0), "полдень");
+    assert_eq!(say(Russian, 13, 0), "час");
+    assert_eq!(say(Russian, 2, 0), "два часа");
+    assert_eq!(say(Russian, 8, 0), "восемь часов");
+    assert_eq!(say(Russian, 8, 15), "четверть девятого");
+    assert_eq!(say(Russian, 8, 30), "половина девятого");
+    assert_eq!(say(Russian, 8, 45), "без четверти девять");
+    assert_eq!(say(Russian, 8, 40), "без двадцати девять");
+    assert_eq!(say(Russian, 8, 10), "десять минут девятого");
+    assert_eq!(say(Russian, 8, 1), "одна минута девятого");
+    assert_eq!(say(Russian, 8, 22), "двадцать две минуты девятого");
+    assert_eq!(say(Russian, 0, 5), "пять минут первого");
+    assert_eq!(say(Russian, 12, 55), "без пяти час");
+    assert_eq!(say(Russian, 8, 57), "без трёх минут девять");
+    assert_eq!(say(Russian, 8, 59), "без одной минуты девять");
+}
+
+#[test]
+fn test_fuzzy() {
+    assert_eq!(English.speak_fuzzy(Clock::new(8, 11)), "about ten past eight");
+    assert_eq!(English.speak_fuzzy(Clock::new(8, 13)), "about quarter past eight");
+    assert_eq!(English.speak_fuzzy(Clock::new(8, 10)), "ten past eight");
+    assert_eq!(English.speak_fuzzy(Clock::new(23, 58)), "about midnight");
+    assert_eq!(German.speak_fuzzy(Clock::new(8, 29)), "etwa halb neun");
+    assert_eq!(French.speak_fuzzy(Clock::new(8, 44)), "environ neuf heures moins le quart");
+    assert_eq!(Russian.speak_fuzzy(Clock::new(8, 16)), "примерно четверть девятого");
+}
+
+#[test]
+fn test_trait_objects() {
+    let languages: [&dyn SpokenLanguage; 4] = [&English, &German, &French, &Russian];
+    let spoken: Vec<String> = languages.iter().map(|language| language.speak(Clock::new(7, 30))).collect();
+    assert_eq!(spoken, ["half past seven", "halb acht", "sept heures et demie", "половина восьмого"]);
+}
@@ -0,0 +1,70 @@
+use clock::{Clock, ClockRange};
+
+fn strings<I: Iterator<Item = Clock>>(clocks: I) -> Vec<String> {
+    clocks.map(|clock| clock.to_string()).collect()
+}
+
+#[test]
+fn test_step_within_the_day() {
+    let steps = Clock::step_by(Clock::new(9, 0), Clock::new(10, 0), 20);
+    assert_eq!(strings(steps), ["09:00", "09:20", "09:40"]);
+}
+
+#[test]
+fn test_step_across_midnight() {
+    let steps = Clock::step_by(Clock::new(22, 30), Clock::new(1, 0), 15);
+    assert_eq!(steps.len(), 10);
+    assert_eq!(
+        strings(steps),
+        ["22:30", "22:45", "23:00", "23:15", "23:30", "23:45", "00:00", "00:15", "00:30", "00:45"]
+    );
+}
+
+#[test]
+fn test_step_not_landing_on_end() {
+    let steps = Clock::step_by_inclusive(Clock::new(22, 30), Clock::new(1, 0), 45);
+    assert_eq!(strings(steps), ["22:30", "23:15", "00:00", "00:45"]);
+}
+
+#[test]
+fn test_inclusive_end() {
+    let exclusive = Clock::step_by(Clock::new(22, 30), Clock::new(1, 0), 15);
+    let mut inclusive = Clock::step_by_inclusive(Clock::new(22, 30), Clock::new(1, 0), 15);
+    assert_eq!(inclusive.len(), exclusive.len() + 1);
+    assert_eq!(inclusive.next_back(), Some(Clock::new(1, 0)));
+}
+
+#[test]
+fn test_equal_start_and_end() {
+    assert_eq!(Clock::step_by(Clock::new(6, 0), Clock::new(6, 0), 15).len(), 0);
+    assert_eq!(strings(Clock::step_by_inclusive(Clock::new(6, 0), Clock::new(6, 0), 15)), ["06:00"]);
+}
+
+#[test]
+fn test_reverse_iteration() {
+    let steps = Clock::step_by(Clock::new(23, 0), Clock::new(1, 0), 30);
+    assert_eq!(strings(steps.rev()), ["00:30", "00:00", "23:30", "23:00"]);
+}
+
+#[test]
+fn test_iterating_from_both_ends() {
+    let mut steps = Clock::step_by_inclusive(Clock::new(8, 0), Clock::new(9, 0), 15);
+    assert_eq!(steps.next(), Some(Clock::new(8, 0)));
+    assert_eq!(steps.next_back(), Some(Clock::new(9, 0)));
+    assert_eq!(steps.len(), 3);
+    assert_eq!(strings(steps), ["08:15", "08:30", "08:45"]);
+}
+
+#[test]
+fn test_full_day_range() {
+    let range = ClockRange::full_day(Clock::new(6, 0));
+    let steps = range.step_by(360);
+    assert_eq!(strings(steps), ["06:00", "12:00", "18:00", "00:00"]);
+    assert_eq!(range.step_by(1).len(), 1440);
+}
+
+#[test]
+#[should_panic]
+fn test_zero_step() {
+    Clock::step_by(Clock::new(8, 0), Clock::new(9, 0), 0);
+}
@@ -0,0 +1,158 @@
+use clock::{Clock, ClockDuration, Countdown, Lap, ManualTimeSource, PreciseClock, Stopwatch};
+
+#[test]
+fn test_new_stopwatch_stands_at_zero() {
+    let time = ManualTimeSource::new();
+    let stopwatch = Stopwatch::new(&time);
+    time.advance(ClockDuration::from_seconds(5));
+    assert!(!stopwatch.is_running());
+    assert_eq!(stopwatch.elapsed(), ClockDuration::ZERO);
+}
+
+#[test]
+fn test_stopwatch_measures_while_running() {
+    let time = ManualTimeSource::new();
+    time.advance(ClockDuration::from_seconds(100));
+    let mut stopwatch = Stopwatch::new(&time);
+    stopwatch.start();
+    time.advance(ClockDuration::from_seconds(90));
+    assert!(stopwatch.is_running());
+    assert_eq!(stopwatch.elapsed(), ClockDuration::from_seconds(90));
+    assert_eq!(stopwatch.elapsed_clock(), PreciseClock::new(0, 1, 30));
+}
+
+#[test]
+fn test_pause_and_resume() {
+    let time = ManualTimeSource::new();
+    let mut stopwatch = Stopwatch::new(&time);
+    stopwatch.start();
+    time.advance(ClockDuration::from_seconds(10));
+    stopwatch.pause();
+    stopwatch.pause();
+    time.advance(ClockDuration::from_seconds(60));
+    assert!(!stopwatch.is_running());
+    assert_eq!(stopwatch.elapsed(), ClockDuration::from_seconds(10));
+    stopwatch.resume();
+    time.advance(ClockDuration::from_seconds(5));
+    stopwatch.resume();
+    time.advance(ClockDuration::from_seconds(5));
+    assert_eq!(stopwatch.elapsed(), ClockDuration::from_seconds(20));
+}
+
+#[test]
+fn test_laps() {
+    let time = ManualTimeSource::new();
+    let mut stopwatch = Stopwatch::new(&time);
+    stopwatch.start();
+    time.advance(ClockDuration::from_seconds(62));
+    assert_eq!(
+        stopwatch.lap(),
+        Lap {
+            number: 1,
+            time: ClockDuration::from_seconds(62),
+            split: ClockDuration::from_seconds(62),
+        }
+    );
+    time.advance(ClockDuration::from_seconds(58));
+    stopwatch.pause();
+    time.advance(ClockDuration::from_seconds(30));
+    assert_eq!(
+        stopwatch.lap(),
+        Lap {
+            number: 2,
+            time: ClockDuration::from_seconds(58),
+            split: ClockDuration::from_seconds(120),
+        }
+    );
+    assert_eq!(stopwatch.laps(), 2);
+}
+
+#[test]
+fn test_reset_and_restart() {
+    let time = ManualTimeSource::new();
+    let mut stopwatch = Stopwatch::new(&time);
+    stopwatch.start();
+    time.advance(ClockDuration::from_seconds(10));
+    stopwatch.lap();
+    stopwatch.reset();
+    assert!(!stopwatch.is_running());
+    assert_eq!((stopwatch.elapsed(), stopwatch.laps()), (ClockDuration::ZERO, 0));
+    stopwatch.start();
+    time.advance(ClockDuration::from_seconds(3));
+    assert_eq!(stopwatch.elapsed(), ClockDuration::from_seconds(3));
+    stopwatch.start();
+    assert_eq!(stopwatch.elapsed(), ClockDuration::ZERO);
+}
+
+#[test]
+fn test_elapsed_clock_wraps_after_a_day() {
+    let time = ManualTimeSource::new();
+    let mut stopwatch = Stopwatch::new(&time);
+    stopwatch.start();
+    time.advance(ClockDuration::from_minutes(25 * 60 + 5));
+    assert_eq!(stopwatch.elapsed_clock().to_clock(), Clock::new(1, 5));
+}
+
+#[test]
+fn test_countdown() {
+    let time = ManualTimeSource::new();
+    let mut countdown = Countdown::new(&time, ClockDuration::from_minutes(5));
+    assert_eq!(countdown.remaining(), ClockDuration::from_minutes(5));
+    countdown.start();
+    time.advance(ClockDuration::from_seconds(90));
+    assert_eq!(countdown.remaining(), ClockDuration::from_seconds(210));
+    assert_eq!(countdown.remaining_clock(), PreciseClock::new(0, 3, 30));
+    countdown.pause();
+    time.advance(ClockDuration::from_seconds(600));
+    assert_eq!(countdown.remaining(), ClockDuration::from_seconds(210));
+    assert!(!countdown.is_finished());
+    countdown.resume();
+    time.advance(ClockDuration::from_seconds(200));
+    assert!(countdown.is_running());
+    time.advance(ClockDuration::from_seconds(10));
+    assert!(countdown.is_finished());
+    assert!(!countdown.is_running());
+    assert_eq!(countdown.remaining(), ClockDuration::ZERO);
+}
+
+#[test]
+fn test_countdown_stops_at_zero() {
+    let time = ManualTimeSource::new();
+    let mut countdown = Countdown::new(&time, ClockDuration::from_seconds(60));
+    countdown.start();
+    time.advance(ClockDuration::from_seconds(45));
+    assert_eq!(countdown.lap().time, ClockDuration::from_seconds(45));
+    time.advance(ClockDuration::from_seconds(45));
+    assert_eq!(countdown.elapsed(), ClockDuration::from_seconds(60));
+    assert_eq!(
+        countdown.lap(),
+        Lap {
+            number: 2,
+            time: ClockDuration::from_seconds(15),
+            split: ClockDuration::from_seconds(60),
+        }
+    );
+    assert_eq!(countdown.lap().time, ClockDuration::ZERO);
+    countdown.reset();
+    assert_eq!(countdown.remaining(), ClockDuration::from_seconds(60));
+    assert!(!countdown.is_running());
+}
+
+#[test]
+#[should_panic]
+fn test_manual_time_cant_go_backwards() {
+    ManualTimeSource::new().advance(ClockDuration::from_seconds(-1));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_system_time_source() {
+    use clock::{SystemTimeSource, TimeSource};
+    let time = SystemTimeSource::new();
+    let (first, second) = (time.now(), time.now());
+    assert!(!first.is_negative() && second >= first);
+    let mut stopwatch = Stopwatch::new(time);
+    stopwatch.start();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    assert!(stopwatch.elapsed() >= ClockDuration::from_nanos(5_000_000));
+}
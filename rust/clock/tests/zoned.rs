@@ -0,0 +1,91 @@
+use clock::{clock, Clock, ParseErrorKind, UtcOffset, ZonedClock};
+
+#[test]
+fn test_offset_range() {
+    assert_eq!(UtcOffset::from_minutes(23 * 60 + 59).map(UtcOffset::minutes), Some(1439));
+    assert_eq!(UtcOffset::from_minutes(-23 * 60 - 59).map(UtcOffset::minutes), Some(-1439));
+    assert_eq!(UtcOffset::from_minutes(24 * 60), None);
+    assert_eq!(UtcOffset::from_hm(-3, -30).map(UtcOffset::minutes), Some(-210));
+    assert_eq!(UtcOffset::from_hm(i32::MAX, 0), None);
+    assert_eq!(UtcOffset::from_minutes(i32::MIN), None);
+    assert_eq!(UtcOffset::from_hm(-35_791_394, -8), None);
+}
+
+#[test]
+fn test_offset_display() {
+    assert_eq!(UtcOffset::from_hm(5, 30).unwrap().to_string(), "+05:30");
+    assert_eq!(UtcOffset::from_hm(-9, -30).unwrap().to_string(), "-09:30");
+    assert_eq!(UtcOffset::UTC.to_string(), "+00:00");
+}
+
+#[test]
+fn test_offset_parse() {
+    assert_eq!("+05:30".parse(), Ok(UtcOffset::from_hm(5, 30).unwrap()));
+    assert_eq!("-0800".parse(), Ok(UtcOffset::from_hm(-8, 0).unwrap()));
+    assert_eq!("+01".parse(), Ok(UtcOffset::from_hm(1, 0).unwrap()));
+    assert_eq!("Z".parse(), Ok(UtcOffset::UTC));
+    let err = "05:30".parse::<UtcOffset>().unwrap_err();
+    assert_eq!((err.position(), err.kind()), (0, ParseErrorKind::UnexpectedChar('0')));
+    let err = "+24:00".parse::<UtcOffset>().unwrap_err();
+    assert_eq!((err.position(), err.kind()), (0, ParseErrorKind::OffsetOutOfRange));
+}
+
+#[test]
+fn test_conversion_within_the_day() {
+    let (berlin, new_york) = (UtcOffset::from_hm(2, 0).unwrap(), UtcOffset::from_hm(-5, 0).unwrap());
+    let (converted, days) = ZonedClock::new(clock!(14:00), berlin).to_offset(new_york);
+    assert_eq!(converted, ZonedClock::new(clock!(07:00), new_york));
+    assert_eq!(days, 0);
+}
+
+#[test]
+fn test_conversion_to_next_day() {
+    let (new_york, delhi) = (UtcOffset::from_hm(-5, 0).unwrap(), UtcOffset::from_hm(5, 30).unwrap());
+    let (converted, days) = ZonedClock::new(clock!(22:00), new_york).to_offset(delhi);
+    assert_eq!(converted, ZonedClock::new(clock!(08:30), delhi));
+    assert_eq!(days, 1);
+}
+
+#[test]
+fn test_conversion_to_previous_day() {
+    let (tokyo, denver) = (UtcOffset::from_hm(9, 0).unwrap(), UtcOffset::from_hm(-7, 0).unwrap());
+    let (converted, days) = ZonedClock::new(clock!(01:15), tokyo).to_offset(denver);
+    assert_eq!(converted, ZonedClock::new(clock!(09:15), denver));
+    assert_eq!(days, -1);
+}
+
+#[test]
+fn test_conversion_to_utc() {
+    assert_eq!(ZonedClock::new(clock!(03:00), UtcOffset::from_hm(5, 30).unwrap()).to_utc(), (Clock::new(21, 30), -1));
+    assert_eq!(ZonedClock::new(clock!(23:00), UtcOffset::from_hm(-2, 0).unwrap()).to_utc(), (Clock::new(1, 0), 1));
+}
+
+#[test]
+fn test_same_time() {
+    let berlin = ZonedClock::new(clock!(14:00), UtcOffset::from_hm(2, 0).unwrap());
+    let new_york = ZonedClock::new(clock!(07:00), UtcOffset::from_hm(-5, 0).unwrap());
+    assert!(berlin.same_time(&new_york));
+    assert!(!berlin.same_time(&ZonedClock::new(clock!(14:00), UtcOffset::UTC)));
+    assert_ne!(berlin, new_york);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(ZonedClock::new(clock!(08:30), UtcOffset::from_hm(5, 30).unwrap()).to_string(), "08:30+05:30");
+    assert_eq!(ZonedClock::new(clock!(23:05), UtcOffset::from_hm(-4, 0).unwrap()).to_string(), "23:05-04:00");
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!("08:30+05:30".parse(), Ok(ZonedClock::new(clock!(08:30), UtcOffset::from_hm(5, 30).unwrap())));
+    assert_eq!("T0830-0800".parse(), Ok(ZonedClock::new(clock!(08:30), UtcOffset::from_hm(-8, 0).unwrap())));
+    assert_eq!("23:59Z".parse(), Ok(ZonedClock::new(clock!(23:59), UtcOffset::UTC)));
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = "08:30".parse::<ZonedClock>().unwrap_err();
+    assert_eq!((err.position(), err.kind()), (5, ParseErrorKind::MissingOffset));
+    let err = "08:30:15+01:00".parse::<ZonedClock>().unwrap_err();
+    assert_eq!((err.position(), err.kind()), (6, ParseErrorKind::TooPrecise));
+}
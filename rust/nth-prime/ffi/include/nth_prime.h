@@ -0,0 +1,66 @@
+/*
+ * C interface to the nth_prime crate.
+ *
+ * Build the library with `cargo build --release -p nth_prime_ffi`, which
+ * produces both `libnth_prime_ffi.so` and `libnth_prime_ffi.a`. When linking
+ * statically, also link against `-lpthread -ldl -lm`.
+ *
+ * Every function returns an `np_status` and writes its result through an
+ * out-pointer. Prime indices are zero-based: np_nth(0, &out) yields 2.
+ */
+
+#ifndef NTH_PRIME_H
+#define NTH_PRIME_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef int32_t np_status;
+
+#define NP_OK 0
+#define NP_ERR_NULL_POINTER 1
+#define NP_ERR_OVERFLOW 2
+#define NP_ERR_INVALID_RANGE 3
+#define NP_ERR_BUFFER_TOO_SMALL 4
+#define NP_ERR_PANIC 5
+
+/* Opaque memoised generator. */
+typedef struct np_primes np_primes;
+
+/* Allocates a generator; returns NULL on failure. */
+np_primes *np_primes_new(void);
+
+/* Releases a generator; NULL is accepted. */
+void np_primes_free(np_primes *handle);
+
+/* Computes the nth (zero-based) prime with a throwaway generator. */
+np_status np_nth(uint32_t n, uint32_t *out);
+
+/* Writes 1 to `out` if `num` is prime, 0 otherwise. */
+np_status np_is_prime(uint32_t num, int32_t *out);
+
+/* Same as np_nth, reusing the primes memoised in `handle`. */
+np_status np_primes_nth(np_primes *handle, uint32_t n, uint32_t *out);
+
+/* Same as np_is_prime, reusing the primes memoised in `handle`. */
+np_status np_primes_is_prime(np_primes *handle, uint32_t num, int32_t *out);
+
+/*
+ * Enumerates the primes in the inclusive range [low, high].
+ *
+ * The total count is always written to `out_len`; up to `capacity` primes are
+ * written to `out`. Pass `out = NULL, capacity = 0` to only query the count.
+ * Returns NP_ERR_BUFFER_TOO_SMALL if the buffer was filled but more remain.
+ */
+np_status np_primes_range(np_primes *handle, uint32_t low, uint32_t high,
+                          uint32_t *out, size_t capacity, size_t *out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* NTH_PRIME_H */
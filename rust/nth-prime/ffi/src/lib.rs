@@ -0,0 +1,7 @@
+//! Static and dynamic C libraries over the [nth_prime](../nth_prime/index.html) crate.
+//!
+//! The functions themselves live in `nth_prime::ffi`, so that they can be tested as a regular
+//! Rust library; this package only exists to build them with the C-compatible crate types,
+//! which the `no_std` core can't have.
+
+pub use nth_prime::ffi::*;
@@ -0,0 +1,83 @@
+/*
+ * Smoke test for the C interface.
+ *
+ *   cargo build --release -p nth_prime_ffi
+ *   cc tests/c/test_nth_prime.c -Iinclude -L../../target/release -l:libnth_prime_ffi.a \
+ *       -lpthread -ldl -lm -o test_nth_prime
+ *   ./test_nth_prime
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "nth_prime.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                     \
+    do {                                                                \
+        if (!(cond)) {                                                  \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,      \
+                    __LINE__, #cond);                                   \
+            failures++;                                                 \
+        }                                                               \
+    } while (0)
+
+static void test_nth(void) {
+    uint32_t out = 0;
+    CHECK(np_nth(0, &out) == NP_OK && out == 2);
+    CHECK(np_nth(5, &out) == NP_OK && out == 13);
+    CHECK(np_nth(10000, &out) == NP_OK && out == 104743);
+    CHECK(np_nth(0, NULL) == NP_ERR_NULL_POINTER);
+}
+
+static void test_is_prime(void) {
+    int32_t out = -1;
+    CHECK(np_is_prime(0, &out) == NP_OK && out == 0);
+    CHECK(np_is_prime(1, &out) == NP_OK && out == 0);
+    CHECK(np_is_prime(2, &out) == NP_OK && out == 1);
+    CHECK(np_is_prime(104743, &out) == NP_OK && out == 1);
+    CHECK(np_is_prime(4294967291u, &out) == NP_OK && out == 1);
+    CHECK(np_is_prime(4294967295u, &out) == NP_OK && out == 0);
+}
+
+static void test_handle(void) {
+    np_primes *primes = np_primes_new();
+    uint32_t nth = 0;
+    int32_t prime = -1;
+    CHECK(primes != NULL);
+    CHECK(np_primes_nth(primes, 10000, &nth) == NP_OK && nth == 104743);
+    CHECK(np_primes_nth(primes, 1, &nth) == NP_OK && nth == 3);
+    CHECK(np_primes_is_prime(primes, 49, &prime) == NP_OK && prime == 0);
+    CHECK(np_primes_is_prime(primes, 97, &prime) == NP_OK && prime == 1);
+    CHECK(np_primes_nth(NULL, 1, &nth) == NP_ERR_NULL_POINTER);
+    np_primes_free(primes);
+    np_primes_free(NULL);
+}
+
+static void test_range(void) {
+    np_primes *primes = np_primes_new();
+    uint32_t buf[4];
+    size_t len = 0;
+    CHECK(np_primes_range(primes, 10, 30, NULL, 0, &len) == NP_OK && len == 6);
+    CHECK(np_primes_range(primes, 10, 30, buf, 4, &len) ==
+          NP_ERR_BUFFER_TOO_SMALL);
+    CHECK(len == 6 && buf[0] == 11 && buf[3] == 19);
+    CHECK(np_primes_range(primes, 0, 7, buf, 4, &len) == NP_OK);
+    CHECK(len == 4 && buf[0] == 2 && buf[3] == 7);
+    CHECK(np_primes_range(primes, 7, 0, buf, 4, &len) == NP_ERR_INVALID_RANGE);
+    np_primes_free(primes);
+}
+
+int main(void) {
+    test_nth();
+    test_is_prime();
+    test_handle();
+    test_range();
+    if (failures) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return EXIT_FAILURE;
+    }
+    puts("all checks passed");
+    return EXIT_SUCCESS;
+}
@@ -0,0 +1,195 @@
+//! Reproducible timing harness for the prime generators.
+//!
+//! Unlike the `bench` test module, this one doesn't need the nightly toolchain. Each measurement
+//! creates a fresh generator, so the runs are independent from each other, and the results are
+//! summarised with order statistics, which are less sensitive to outliers than the mean.
+
+use crate::generator;
+use std::fmt::{self, Display, Formatter};
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Parameters of a benchmark session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Names of the generators to measure, see [generator_names](../fn.generator_names.html).
+    pub generators: Vec<String>,
+    /// Indices of the primes to compute.
+    pub ns: Vec<u32>,
+    /// Number of untimed runs before the measurements.
+    pub warmup: u32,
+    /// Number of timed runs.
+    pub runs: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            generators: crate::generator_names().into_iter().map(String::from).collect(),
+            ns: vec![10, 100, 1000],
+            warmup: 3,
+            runs: 15,
+        }
+    }
+}
+
+/// Order statistics of a set of timings, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub max: u64,
+    pub mean: u64,
+}
+
+impl Stats {
+    /// Summarises the provided samples. Returns None if there are none.
+    pub fn from_samples(samples: &[u64]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let total: u128 = sorted.iter().map(|&s| u128::from(s)).sum();
+        Some(Stats {
+            min: sorted[0],
+            median: percentile(&sorted, 50),
+            p90: percentile(&sorted, 90),
+            p99: percentile(&sorted, 99),
+            max: sorted[sorted.len() - 1],
+            mean: (total / sorted.len() as u128) as u64,
+        })
+    }
+}
+
+/// Nearest-rank percentile of the already sorted, non-empty samples.
+fn percentile(sorted: &[u64], pct: usize) -> u64 {
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Timings of a single generator for a single index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub generator: String,
+    pub n: u32,
+    /// Computed prime, or None if it doesn't fit in u32.
+    pub prime: Option<u32>,
+    pub stats: Stats,
+}
+
+/// Full results of a benchmark session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub config: BenchConfig,
+    pub measurements: Vec<Measurement>,
+}
+
+/// Error returned when the configuration can't be run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BenchError {
+    UnknownGenerator(String),
+    NoRuns,
+}
+
+impl Display for BenchError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            BenchError::UnknownGenerator(name) => write!(f, "unknown generator \"{}\"", name),
+            BenchError::NoRuns => write!(f, "at least one timed run is required"),
+        }
+    }
+}
+
+impl std::error::Error for BenchError {}
+
+/// Runs every configured generator over every configured index.
+pub fn run(config: &BenchConfig) -> Result<Report, BenchError> {
+    if config.runs == 0 {
+        return Err(BenchError::NoRuns);
+    }
+    if let Some(name) = config.generators.iter().find(|name| generator(name).is_none()) {
+        return Err(BenchError::UnknownGenerator(name.clone()));
+    }
+    let mut measurements = Vec::new();
+    for name in &config.generators {
+        for &n in &config.ns {
+            let make = || generator(name).expect("generator names are validated above");
+            for _ in 0..config.warmup {
+                black_box(make().nth(black_box(n)));
+            }
+            let mut prime = None;
+            let samples: Vec<u64> = (0..config.runs)
+                .map(|_| {
+                    let mut primes = make();
+                    let now = Instant::now();
+                    prime = black_box(primes.nth(black_box(n))).map(u32::from);
+                    now.elapsed().as_nanos() as u64
+                })
+                .collect();
+            measurements.push(Measurement {
+                generator: name.clone(),
+                n,
+                prime,
+                stats: Stats::from_samples(&samples).expect("runs are checked to be non-zero"),
+            });
+        }
+    }
+    Ok(Report {
+        config: config.clone(),
+        measurements,
+    })
+}
+
+impl Report {
+    /// Serialises the report as a single-line JSON object.
+    pub fn to_json(&self) -> String {
+        let generators: Vec<String> = self.config.generators.iter().map(|g| json_string(g)).collect();
+        let ns: Vec<String> = self.config.ns.iter().map(u32::to_string).collect();
+        let measurements: Vec<String> = self.measurements.iter().map(Measurement::to_json).collect();
+        format!(
+            "{{\"version\":{},\"config\":{{\"generators\":[{}],\"ns\":[{}],\"warmup\":{},\"runs\":{}}},\"results\":[{}]}}",
+            json_string(env!("CARGO_PKG_VERSION")),
+            generators.join(","),
+            ns.join(","),
+            self.config.warmup,
+            self.config.runs,
+            measurements.join(",")
+        )
+    }
+}
+
+impl Measurement {
+    fn to_json(&self) -> String {
+        let s = &self.stats;
+        format!(
+            "{{\"generator\":{},\"n\":{},\"prime\":{},\"nanos\":{{\"min\":{},\"median\":{},\"p90\":{},\"p99\":{},\"max\":{},\"mean\":{}}}}}",
+            json_string(&self.generator),
+            self.n,
+            self.prime.map_or_else(|| "null".to_string(), |p| p.to_string()),
+            s.min,
+            s.median,
+            s.p90,
+            s.p99,
+            s.max,
+            s.mean
+        )
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
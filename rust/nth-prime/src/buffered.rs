@@ -0,0 +1,90 @@
+use crate::{PrimeGenerator, PRIMES_IN_U32};
+use core::num::NonZeroU32;
+
+/// Memoising generator backed by a caller-provided buffer.
+///
+/// It works like [PrimesMemoed](struct.PrimesMemoed.html), but never allocates: the primes are
+/// stored in the buffer until it's full, and past that point the generator falls back to the trial
+/// division for the divisors which didn't fit. This makes it usable without the standard library.
+#[derive(Debug)]
+pub struct PrimesBuffered<'a> {
+    memo: &'a mut [u32],
+    len: usize,
+}
+
+impl<'a> PrimesBuffered<'a> {
+    /// Creates the generator over the buffer. The previous content of the buffer is ignored.
+    ///
+    /// An empty buffer is accepted, in which case nothing is memoised.
+    pub fn new(buffer: &'a mut [u32]) -> Self {
+        let len = match buffer.first_mut() {
+            Some(first) => {
+                *first = 2;
+                1
+            }
+            None => 0,
+        };
+        PrimesBuffered { memo: buffer, len }
+    }
+
+    /// Primes memoised so far, in increasing order, starting from 2.
+    pub fn memoised(&self) -> &[u32] {
+        &self.memo[..self.len]
+    }
+
+    /// Whether the buffer has no space left.
+    pub fn is_full(&self) -> bool {
+        self.len == self.memo.len()
+    }
+
+    fn last(&self) -> Option<u32> {
+        self.memoised().last().cloned()
+    }
+}
+
+impl<'a> PrimeGenerator for PrimesBuffered<'a> {
+    fn predicate(&self, num: u32) -> bool {
+        let num = u64::from(num);
+        let fits = |div: u64| div * div <= num;
+        if num < 2 {
+            return true;
+        }
+        if self
+            .memoised()
+            .iter()
+            .map(|&div| u64::from(div))
+            .take_while(|&div| fits(div))
+            .any(|div| num.is_multiple_of(div))
+        {
+            return true;
+        }
+        // Continue with the numbers past the memo, if it doesn't reach the square root.
+        let start = match self.last() {
+            Some(last) => u64::from(last) + 1,
+            None => 2,
+        };
+        (start..)
+            .take_while(|&div| fits(div))
+            .any(|div| num.is_multiple_of(div))
+    }
+    fn next_after(&mut self, last: u32) -> Option<NonZeroU32> {
+        let next = self.next_after_pure(last);
+        if let Some(next) = next {
+            if self.last() == Some(last) && !self.is_full() {
+                self.memo[self.len] = next.into();
+                self.len += 1;
+            }
+        }
+        next
+    }
+    fn nth(&mut self, n: u32) -> Option<NonZeroU32> {
+        if n >= PRIMES_IN_U32 {
+            return None;
+        }
+        match (self.memoised().get(n as usize).cloned(), self.last()) {
+            (Some(val), _) => NonZeroU32::new(val),
+            (None, Some(last)) => self.nth_after(n + 1 - self.len as u32, last),
+            (None, None) => self.nth_after(n, 2),
+        }
+    }
+}
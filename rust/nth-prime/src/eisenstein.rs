@@ -0,0 +1,212 @@
+//! Eisenstein integers, the numbers `a + bω` with integer `a` and `b`, where `ω = (-1 + √-3) / 2`
+//! is a primitive cube root of unity.
+//!
+//! An Eisenstein integer is prime if its norm is a rational prime (3 or `p ≡ 1 (mod 3)`), or if it is
+//! an associate of a rational prime `p ≡ 2 (mod 3)`. Both checks are done with the [Miller-Rabin test](../miller_rabin/index.html).
+
+use crate::gaussian::{div_round, narrow};
+use crate::{exact_sqrt, miller_rabin};
+use core::convert::TryFrom;
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Eisenstein integer `a + b * ω`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Eisenstein {
+    pub a: i32,
+    pub b: i32,
+}
+
+impl Eisenstein {
+    pub const ZERO: Eisenstein = Eisenstein::new(0, 0);
+    pub const ONE: Eisenstein = Eisenstein::new(1, 0);
+    pub const OMEGA: Eisenstein = Eisenstein::new(0, 1);
+    /// The six units, in counterclockwise order: `1, -ω², ω, -1, ω², -ω`.
+    pub const UNITS: [Eisenstein; 6] = [
+        Eisenstein::new(1, 0),
+        Eisenstein::new(1, 1),
+        Eisenstein::new(0, 1),
+        Eisenstein::new(-1, 0),
+        Eisenstein::new(-1, -1),
+        Eisenstein::new(0, -1),
+    ];
+
+    pub const fn new(a: i32, b: i32) -> Self {
+        Eisenstein { a, b }
+    }
+
+    /// The norm `a^2 - ab + b^2`, i.e. the product with the conjugate.
+    pub fn norm(self) -> u64 {
+        let (a, b) = (i128::from(self.a), i128::from(self.b));
+        // Below 3 * 2^62 for any i32 coordinates, so it fits once computed.
+        (a * a - a * b + b * b) as u64
+    }
+
+    /// The complex conjugate, `(a - b) - bω`.
+    pub fn conj(self) -> Self {
+        Eisenstein::new(self.a - self.b, -self.b)
+    }
+
+    pub fn is_unit(self) -> bool {
+        self.norm() == 1
+    }
+
+    /// Checks whether the number is an Eisenstein prime.
+    pub fn is_prime(self) -> bool {
+        let norm = self.norm();
+        // Every element with the norm p^2 for an inert p is an associate of p.
+        miller_rabin::is_prime(norm) || exact_sqrt(norm).is_some_and(|p| p % 3 == 2 && miller_rabin::is_prime(p))
+    }
+
+    /// The associate with the argument in `[0, π/3)`, i.e. with `a > b >= 0`.
+    ///
+    /// Zero is returned unchanged.
+    pub fn normalized(self) -> Self {
+        let mut z = self;
+        if z == Eisenstein::ZERO {
+            return z;
+        }
+        while !(z.a > z.b && z.b >= 0) {
+            z = z * Eisenstein::UNITS[1];
+        }
+        z
+    }
+
+    /// Divides by `other` if it's an exact divisor. Returns None otherwise, or if `other` is zero.
+    pub fn checked_div(self, other: Eisenstein) -> Option<Self> {
+        let norm = i128::from(other.norm());
+        if norm == 0 {
+            return None;
+        }
+        let (a, b) = self.mul_conj(other);
+        if a % norm == 0 && b % norm == 0 {
+            Some(Eisenstein::new(i32::try_from(a / norm).ok()?, i32::try_from(b / norm).ok()?))
+        } else {
+            None
+        }
+    }
+
+    /// Euclidean division, rounding both coordinates of the exact quotient to the nearest integer.
+    ///
+    /// The remainder always has a norm at most three quarters of the norm of `other`.
+    ///
+    /// # Panics
+    /// This function will panic if `other` is zero.
+    pub fn div_rem(self, other: Eisenstein) -> (Self, Self) {
+        let norm = i128::from(other.norm());
+        assert!(norm != 0, "Division by zero Eisenstein integer");
+        let (a, b) = self.mul_conj(other);
+        let quotient = Eisenstein::new(narrow(div_round(a, norm)), narrow(div_round(b, norm)));
+        // The product of the quotient and `other` may not fit in i32, unlike the remainder.
+        let (c, d) = mul_wide(quotient, other.a.into(), other.b.into());
+        let rem = Eisenstein::new(narrow(i128::from(self.a) - c), narrow(i128::from(self.b) - d));
+        (quotient, rem)
+    }
+
+    /// Greatest common divisor, normalized with [normalized](#method.normalized).
+    pub fn gcd(self, other: Eisenstein) -> Self {
+        let (mut x, mut y) = (self, other);
+        while y != Eisenstein::ZERO {
+            let (_, rem) = x.div_rem(y);
+            x = y;
+            y = rem;
+        }
+        x.normalized()
+    }
+
+    /// Coordinates of `self * conj(other)`, widened to avoid the overflow.
+    fn mul_conj(self, other: Eisenstein) -> (i128, i128) {
+        // The conjugate itself may not fit in i32, so it's taken on the widened coordinates.
+        let (c, d) = (i128::from(other.a), i128::from(other.b));
+        mul_wide(self, c - d, -d)
+    }
+}
+
+fn mul_wide(x: Eisenstein, c: i128, d: i128) -> (i128, i128) {
+    // ω^2 = -1 - ω
+    let (a, b) = (i128::from(x.a), i128::from(x.b));
+    (a * c - b * d, a * d + b * c - b * d)
+}
+
+impl From<i32> for Eisenstein {
+    fn from(a: i32) -> Self {
+        Eisenstein::new(a, 0)
+    }
+}
+
+impl Add for Eisenstein {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Eisenstein::new(self.a + other.a, self.b + other.b)
+    }
+}
+
+impl Sub for Eisenstein {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Eisenstein::new(self.a - other.a, self.b - other.b)
+    }
+}
+
+impl Mul for Eisenstein {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let (a, b) = mul_wide(self, other.a.into(), other.b.into());
+        Eisenstein::new(narrow(a), narrow(b))
+    }
+}
+
+impl Neg for Eisenstein {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Eisenstein::new(-self.a, -self.b)
+    }
+}
+
+impl Display for Eisenstein {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match (self.a, self.b) {
+            (a, 0) => write!(f, "{}", a),
+            (0, b) => write!(f, "{}ω", b),
+            (a, b) if b < 0 => write!(f, "{} - {}ω", a, -i64::from(b)),
+            (a, b) => write!(f, "{} + {}ω", a, b),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod factor {
+    use super::Eisenstein;
+    use crate::factorize::{self, Factorization};
+    use crate::miller_rabin;
+
+    /// Finds the normalized Eisenstein prime of norm `p`, for a rational prime `p ≡ 1 (mod 3)`.
+    fn split(p: u64) -> Eisenstein {
+        // A primitive cube root of unity w modulo p gives 2w + 1 as a square root of -3.
+        let cube_root = (2..p)
+            .map(|c| miller_rabin::pow_mod(c, (p - 1) / 3, p))
+            .find(|&w| w != 1)
+            .expect("p = 1 mod 3 must have a primitive cube root of unity");
+        let (x, y) = factorize::cornacchia(3, p, (2 * u128::from(cube_root) + 1) as u64 % p);
+        // x^2 + 3y^2 = (x + y)^2 - (x + y) * 2y + (2y)^2
+        Eisenstein::new((x + y) as i32, (2 * y) as i32).normalized()
+    }
+
+    impl Eisenstein {
+        /// Factorizes the number into a unit and normalized Eisenstein primes. Returns None for zero.
+        pub fn factorize(self) -> Option<Factorization<Eisenstein>> {
+            if self == Eisenstein::ZERO {
+                return None;
+            }
+            let candidates = |p: u64| match p % 3 {
+                0 => vec![Eisenstein::new(2, 1)],
+                2 => vec![Eisenstein::new(p as i32, 0)],
+                _ => {
+                    let prime = split(p);
+                    vec![prime, prime.conj().normalized()]
+                }
+            };
+            Some(factorize::by_norm(self, self.norm(), candidates, Eisenstein::checked_div))
+        }
+    }
+}
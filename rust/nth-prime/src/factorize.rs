@@ -0,0 +1,136 @@
+//! Factorization support for the algebraic integer modules.
+
+use crate::exact_sqrt;
+use crate::miller_rabin::{self, mul_mod};
+use std::ops::Mul;
+
+/// Factorization of a non-zero algebraic integer into a unit and normalized primes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Factorization<T> {
+    pub unit: T,
+    /// Distinct normalized primes with their multiplicities, ordered by norm.
+    pub factors: Vec<(T, u32)>,
+}
+
+impl<T: Copy + Mul<Output = T>> Factorization<T> {
+    /// Multiplies the factors back together.
+    pub fn product(&self) -> T {
+        self.factors
+            .iter()
+            .flat_map(|&(p, k)| std::iter::repeat_n(p, k as usize))
+            .fold(self.unit, |acc, p| acc * p)
+    }
+}
+
+/// Factorizes `num` by dividing out, for every rational prime `p` of its norm, the primes
+/// lying over `p` as provided by `candidates`. Whatever remains is the unit.
+pub(crate) fn by_norm<T, C, D>(num: T, norm: u64, candidates: C, checked_div: D) -> Factorization<T>
+where
+    T: Copy,
+    C: Fn(u64) -> Vec<T>,
+    D: Fn(T, T) -> Option<T>,
+{
+    let mut rest = num;
+    let mut factors = Vec::new();
+    for (p, _) in rational_factors(norm) {
+        for prime in candidates(p) {
+            let mut count = 0;
+            while let Some(quotient) = checked_div(rest, prime) {
+                rest = quotient;
+                count += 1;
+            }
+            if count > 0 {
+                factors.push((prime, count));
+            }
+        }
+    }
+    Factorization { unit: rest, factors }
+}
+
+/// Divisors below this bound are found by trial division, larger ones with Pollard's rho.
+const TRIAL_BOUND: u64 = 1000;
+
+/// Factorizes the number into distinct rational primes with multiplicities, in increasing order.
+///
+/// Zero and one have no prime factors.
+pub(crate) fn rational_factors(mut num: u64) -> Vec<(u64, u32)> {
+    let mut primes = Vec::new();
+    if num == 0 {
+        return Vec::new();
+    }
+    for div in 2..TRIAL_BOUND {
+        while num.is_multiple_of(div) {
+            primes.push(div);
+            num /= div;
+        }
+    }
+    let mut stack = vec![num];
+    while let Some(composite) = stack.pop() {
+        if composite == 1 {
+            continue;
+        }
+        if miller_rabin::is_prime(composite) {
+            primes.push(composite);
+            continue;
+        }
+        let div = pollard_rho(composite);
+        stack.push(div);
+        stack.push(composite / div);
+    }
+    primes.sort_unstable();
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, count)) if *last == p => *count += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Finds a non-trivial divisor of an odd composite number, which has no divisors below `TRIAL_BOUND`.
+fn pollard_rho(num: u64) -> u64 {
+    if let Some(root) = exact_sqrt(num) {
+        return root;
+    }
+    for c in 1u128.. {
+        let step = |x: u64| ((u128::from(mul_mod(x, x, num)) + c) % u128::from(num)) as u64;
+        let (mut x, mut y, mut div) = (2, 2, 1);
+        while div == 1 {
+            x = step(x);
+            y = step(step(y));
+            div = gcd(x.abs_diff(y), num);
+        }
+        if div != num {
+            return div;
+        }
+    }
+    unreachable!("some polynomial always splits a composite number")
+}
+
+/// Solves `x^2 + d * y^2 = p` for a prime `p`, given `root` with `root^2 ≡ -d (mod p)`.
+///
+/// This is Cornacchia's algorithm: the Euclidean algorithm on `p` and `root` is stopped at
+/// the first remainder below `sqrt(p)`, which is `x`.
+pub(crate) fn cornacchia(d: u64, p: u64, root: u64) -> (u64, u64) {
+    let (mut a, mut b) = (p, root.min(p - root));
+    while b.checked_mul(b).is_none_or(|square| square >= p) {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    let rest = p - b * b;
+    let y = exact_sqrt(rest / d)
+        .filter(|_| rest.is_multiple_of(d))
+        .expect("Cornacchia's algorithm needs a prime and a square root of -d modulo it");
+    (b, y)
+}
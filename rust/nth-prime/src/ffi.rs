@@ -0,0 +1,176 @@
+//! C ABI for the prime generators.
+//!
+//! Enabled by the "ffi" feature (which implies "memoed"). Every function returns an
+//! [np_status](type.np_status.html) code and writes its result through an out-pointer, so that
+//! no panic ever crosses the FFI boundary. The matching declarations live in `ffi/include/nth_prime.h`,
+//! and the `nth_prime_ffi` package in the same directory builds the C libraries.
+//!
+//! Indices are zero-based everywhere, in line with the [nth](../fn.nth.html) function:
+//! `np_nth(0, &out)` yields 2.
+
+#![allow(non_camel_case_types)]
+
+use crate::{PrimeGenerator, PrimesMemoed};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// Status code returned by every exported function.
+pub type np_status = i32;
+
+/// The call succeeded.
+pub const NP_OK: np_status = 0;
+/// A required pointer argument was null.
+pub const NP_ERR_NULL_POINTER: np_status = 1;
+/// The requested prime doesn't fit in the `uint32_t` range.
+pub const NP_ERR_OVERFLOW: np_status = 2;
+/// The range bounds are reversed.
+pub const NP_ERR_INVALID_RANGE: np_status = 3;
+/// The output buffer is too small; the required length is still reported.
+pub const NP_ERR_BUFFER_TOO_SMALL: np_status = 4;
+/// The generator panicked internally. The handle, if any, should be freed.
+pub const NP_ERR_PANIC: np_status = 5;
+
+/// Opaque handle, wrapping the memoised generator.
+///
+/// Created by [np_primes_new](fn.np_primes_new.html) and released by [np_primes_free](fn.np_primes_free.html).
+pub struct np_primes {
+    inner: PrimesMemoed,
+}
+
+fn guard<F: FnOnce() -> np_status>(f: F) -> np_status {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(NP_ERR_PANIC)
+}
+
+/// Allocates a new generator handle. Returns null if the allocation panicked.
+#[no_mangle]
+pub extern "C" fn np_primes_new() -> *mut np_primes {
+    panic::catch_unwind(|| {
+        Box::into_raw(Box::new(np_primes {
+            inner: PrimesMemoed::new(),
+        }))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Releases a generator handle. Passing null is a no-op.
+///
+/// # Safety
+/// `handle` must be null or a pointer returned by `np_primes_new` which wasn't freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn np_primes_free(handle: *mut np_primes) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Computes the nth (zero-based) prime with a throwaway generator.
+///
+/// # Safety
+/// `out` must be null or valid for writing a single `uint32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn np_nth(n: u32, out: *mut u32) -> np_status {
+    if out.is_null() {
+        return NP_ERR_NULL_POINTER;
+    }
+    guard(|| match PrimesMemoed::new().nth(n) {
+        Some(prime) => {
+            *out = prime.into();
+            NP_OK
+        }
+        None => NP_ERR_OVERFLOW,
+    })
+}
+
+/// Checks whether `num` is prime. Writes 1 or 0 to `out`.
+///
+/// # Safety
+/// `out` must be null or valid for writing a single `int`.
+#[no_mangle]
+pub unsafe extern "C" fn np_is_prime(num: u32, out: *mut i32) -> np_status {
+    if out.is_null() {
+        return NP_ERR_NULL_POINTER;
+    }
+    guard(|| {
+        *out = i32::from(crate::is_prime(num));
+        NP_OK
+    })
+}
+
+/// Computes the nth (zero-based) prime, reusing the primes memoised in the handle.
+///
+/// # Safety
+/// `handle` must be null or a live handle, `out` must be null or valid for writing a single `uint32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn np_primes_nth(handle: *mut np_primes, n: u32, out: *mut u32) -> np_status {
+    if handle.is_null() || out.is_null() {
+        return NP_ERR_NULL_POINTER;
+    }
+    let primes = &mut (*handle).inner;
+    guard(|| match primes.nth(n) {
+        Some(prime) => {
+            *out = prime.into();
+            NP_OK
+        }
+        None => NP_ERR_OVERFLOW,
+    })
+}
+
+/// Checks whether `num` is prime, reusing the primes memoised in the handle. Writes 1 or 0 to `out`.
+///
+/// # Safety
+/// `handle` must be null or a live handle, `out` must be null or valid for writing a single `int`.
+#[no_mangle]
+pub unsafe extern "C" fn np_primes_is_prime(handle: *mut np_primes, num: u32, out: *mut i32) -> np_status {
+    if handle.is_null() || out.is_null() {
+        return NP_ERR_NULL_POINTER;
+    }
+    let primes = &mut (*handle).inner;
+    guard(|| {
+        *out = i32::from(primes.is_prime(num));
+        NP_OK
+    })
+}
+
+/// Enumerates the primes in the inclusive range `[low, high]`.
+///
+/// The total number of primes in the range is always written to `out_len`. Up to `capacity`
+/// of them are written to `out`, which may be null (with zero capacity) to only query the length.
+/// If the buffer is too small, `NP_ERR_BUFFER_TOO_SMALL` is returned after filling it.
+///
+/// # Safety
+/// `handle` must be null or a live handle, `out_len` must be null or valid for writing a single `size_t`,
+/// `out` must be null or valid for writing `capacity` values of `uint32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn np_primes_range(
+    handle: *mut np_primes,
+    low: u32,
+    high: u32,
+    out: *mut u32,
+    capacity: usize,
+    out_len: *mut usize,
+) -> np_status {
+    if handle.is_null() || out_len.is_null() || (out.is_null() && capacity != 0) {
+        return NP_ERR_NULL_POINTER;
+    }
+    if low > high {
+        return NP_ERR_INVALID_RANGE;
+    }
+    let primes = &mut (*handle).inner;
+    guard(|| {
+        let mut len = 0;
+        for num in low..=high {
+            if primes.is_prime(num) {
+                if len < capacity {
+                    *out.add(len) = num;
+                }
+                len += 1;
+            }
+        }
+        *out_len = len;
+        if len > capacity && !out.is_null() {
+            NP_ERR_BUFFER_TOO_SMALL
+        } else {
+            NP_OK
+        }
+    })
+}
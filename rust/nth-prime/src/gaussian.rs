@@ -0,0 +1,265 @@
+//! Gaussian integers, the numbers `a + bi` with integer `a` and `b`.
+//!
+//! A Gaussian integer is prime if its norm is a rational prime, or if it is an associate
+//! of a rational prime `p ≡ 3 (mod 4)`. Both checks are done with the [Miller-Rabin test](../miller_rabin/index.html).
+
+use crate::{exact_sqrt, miller_rabin};
+use core::convert::TryFrom;
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Gaussian integer `re + im * i`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gaussian {
+    pub re: i32,
+    pub im: i32,
+}
+
+impl Gaussian {
+    pub const ZERO: Gaussian = Gaussian::new(0, 0);
+    pub const ONE: Gaussian = Gaussian::new(1, 0);
+    pub const I: Gaussian = Gaussian::new(0, 1);
+    /// The four units, `1, i, -1, -i`.
+    pub const UNITS: [Gaussian; 4] = [Gaussian::new(1, 0), Gaussian::new(0, 1), Gaussian::new(-1, 0), Gaussian::new(0, -1)];
+
+    pub const fn new(re: i32, im: i32) -> Self {
+        Gaussian { re, im }
+    }
+
+    /// The norm `re^2 + im^2`, i.e. the product with the conjugate.
+    pub fn norm(self) -> u64 {
+        let (re, im) = (u64::from(self.re.unsigned_abs()), u64::from(self.im.unsigned_abs()));
+        // At most 2^63, even for i32::MIN in both parts.
+        re * re + im * im
+    }
+
+    pub fn conj(self) -> Self {
+        Gaussian::new(self.re, -self.im)
+    }
+
+    pub fn is_unit(self) -> bool {
+        self.norm() == 1
+    }
+
+    /// Checks whether the number is a Gaussian prime.
+    pub fn is_prime(self) -> bool {
+        match (self.re.unsigned_abs(), self.im.unsigned_abs()) {
+            (0, 0) => false,
+            (0, p) | (p, 0) => p % 4 == 3 && miller_rabin::is_prime(u64::from(p)),
+            _ => miller_rabin::is_prime(self.norm()),
+        }
+    }
+
+    /// The associate lying in the first quadrant, i.e. with `re > 0` and `im >= 0`.
+    ///
+    /// Zero is returned unchanged.
+    pub fn normalized(self) -> Self {
+        let mut z = self;
+        if z == Gaussian::ZERO {
+            return z;
+        }
+        while !(z.re > 0 && z.im >= 0) {
+            z = z * Gaussian::I;
+        }
+        z
+    }
+
+    /// Divides by `other` if it's an exact divisor. Returns None otherwise, or if `other` is zero.
+    pub fn checked_div(self, other: Gaussian) -> Option<Self> {
+        let norm = i128::from(other.norm());
+        if norm == 0 {
+            return None;
+        }
+        let (re, im) = self.mul_conj(other);
+        if re % norm == 0 && im % norm == 0 {
+            Some(Gaussian::new(i32::try_from(re / norm).ok()?, i32::try_from(im / norm).ok()?))
+        } else {
+            None
+        }
+    }
+
+    /// Euclidean division, rounding the exact quotient to the nearest Gaussian integer.
+    ///
+    /// The remainder always has a norm at most half the norm of `other`.
+    ///
+    /// # Panics
+    /// This function will panic if `other` is zero.
+    pub fn div_rem(self, other: Gaussian) -> (Self, Self) {
+        let norm = i128::from(other.norm());
+        assert!(norm != 0, "Division by zero Gaussian integer");
+        let (re, im) = self.mul_conj(other);
+        let (q_re, q_im) = (div_round(re, norm), div_round(im, norm));
+        // The product of the quotient and `other` may not fit in i32, unlike the remainder.
+        let (c, d) = (i128::from(other.re), i128::from(other.im));
+        let rem_re = i128::from(self.re) - (q_re * c - q_im * d);
+        let rem_im = i128::from(self.im) - (q_re * d + q_im * c);
+        (Gaussian::new(narrow(q_re), narrow(q_im)), Gaussian::new(narrow(rem_re), narrow(rem_im)))
+    }
+
+    /// Greatest common divisor, normalized to the first quadrant.
+    pub fn gcd(self, other: Gaussian) -> Self {
+        let (mut a, mut b) = (self, other);
+        while b != Gaussian::ZERO {
+            let (_, rem) = a.div_rem(b);
+            a = b;
+            b = rem;
+        }
+        a.normalized()
+    }
+
+    /// Components of `self * conj(other)`, widened to avoid the overflow.
+    fn mul_conj(self, other: Gaussian) -> (i128, i128) {
+        let (a, b) = (i128::from(self.re), i128::from(self.im));
+        let (c, d) = (i128::from(other.re), i128::from(other.im));
+        (a * c + b * d, b * c - a * d)
+    }
+}
+
+/// Division rounding to the nearest integer, with ties away from zero.
+pub(crate) fn div_round(num: i128, den: i128) -> i128 {
+    let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+    if num >= 0 {
+        (2 * num + den) / (2 * den)
+    } else {
+        -((-2 * num + den) / (2 * den))
+    }
+}
+
+impl From<i32> for Gaussian {
+    fn from(re: i32) -> Self {
+        Gaussian::new(re, 0)
+    }
+}
+
+impl Add for Gaussian {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Gaussian::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Gaussian {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Gaussian::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Gaussian {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let (a, b) = (i128::from(self.re), i128::from(self.im));
+        let (c, d) = (i128::from(other.re), i128::from(other.im));
+        Gaussian::new(narrow(a * c - b * d), narrow(a * d + b * c))
+    }
+}
+
+/// Narrows the widened intermediate result back, panicking like the built-in arithmetic on overflow.
+pub(crate) fn narrow(value: i128) -> i32 {
+    i32::try_from(value).expect("attempt to multiply with overflow")
+}
+
+impl Neg for Gaussian {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Gaussian::new(-self.re, -self.im)
+    }
+}
+
+impl Display for Gaussian {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match (self.re, self.im) {
+            (re, 0) => write!(f, "{}", re),
+            (0, im) => write!(f, "{}i", im),
+            (re, im) if im < 0 => write!(f, "{} - {}i", re, -i64::from(im)),
+            (re, im) => write!(f, "{} + {}i", re, im),
+        }
+    }
+}
+
+/// Iterator over the Gaussian primes in the first quadrant (`re > 0`, `im >= 0`),
+/// ordered by norm and then by the imaginary part.
+///
+/// Every Gaussian prime is an associate of exactly one of the yielded values.
+#[derive(Debug, Clone)]
+pub struct GaussianPrimes {
+    norm: u64,
+    im: u64,
+}
+
+impl GaussianPrimes {
+    pub fn new() -> Self {
+        GaussianPrimes { norm: 2, im: 0 }
+    }
+}
+
+impl Default for GaussianPrimes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether some Gaussian prime has this norm: a rational prime, or the square of one `≡ 3 (mod 4)`.
+fn is_prime_norm(norm: u64) -> bool {
+    miller_rabin::is_prime(norm) || exact_sqrt(norm).is_some_and(|p| p % 4 == 3 && miller_rabin::is_prime(p))
+}
+
+impl Iterator for GaussianPrimes {
+    type Item = Gaussian;
+    fn next(&mut self) -> Option<Gaussian> {
+        loop {
+            if self.norm > 2 * u64::from(i32::MAX as u32).pow(2) {
+                return None;
+            }
+            if is_prime_norm(self.norm) {
+                while self.im * self.im < self.norm {
+                    let im = self.im;
+                    self.im += 1;
+                    if let Some(re) = exact_sqrt(self.norm - im * im) {
+                        if re <= i32::MAX as u64 && im <= i32::MAX as u64 {
+                            return Some(Gaussian::new(re as i32, im as i32));
+                        }
+                    }
+                }
+            }
+            self.norm += 1;
+            self.im = 0;
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod factor {
+    use super::Gaussian;
+    use crate::factorize::{self, Factorization};
+    use crate::miller_rabin;
+
+    /// Finds the first-quadrant Gaussian prime of norm `p`, for a rational prime `p ≡ 1 (mod 4)`.
+    fn split(p: u64) -> Gaussian {
+        // Any quadratic non-residue c gives c^((p - 1) / 4) as a square root of -1 modulo p.
+        let root = (2..p)
+            .map(|c| miller_rabin::pow_mod(c, (p - 1) / 4, p))
+            .find(|&x| miller_rabin::mul_mod(x, x, p) == p - 1)
+            .expect("p = 1 mod 4 must have a square root of -1");
+        let (re, im) = factorize::cornacchia(1, p, root);
+        Gaussian::new(re as i32, im as i32)
+    }
+
+    impl Gaussian {
+        /// Factorizes the number into a unit and first-quadrant Gaussian primes. Returns None for zero.
+        pub fn factorize(self) -> Option<Factorization<Gaussian>> {
+            if self == Gaussian::ZERO {
+                return None;
+            }
+            let candidates = |p: u64| match p % 4 {
+                2 => vec![Gaussian::new(1, 1)],
+                3 => vec![Gaussian::new(p as i32, 0)],
+                _ => {
+                    let prime = split(p);
+                    vec![prime, prime.conj().normalized()]
+                }
+            };
+            Some(factorize::by_norm(self, self.norm(), candidates, Gaussian::checked_div))
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(all(feature = "nightly", feature = "memoed"), feature(test))]
 
 //! Crate for runtime prime numbers generation.
@@ -6,8 +7,39 @@
 //! You can opt in touse memoisation by activating "memoed" feature, which exports the 
 //! `PrimesMemoed` structure. It works considerably faster for large numbers and for several
 //! searches in a row, at the cost of storing all already found primes in memory.
+//!
+//! The crate is split into a `no_std` core and a layer which needs the standard library.
+//! The core, always available, contains the trait, the `Primes` and `PrimesMillerRabin` generators,
+//! the [miller_rabin](miller_rabin/index.html) test and the [PrimesBuffered](struct.PrimesBuffered.html)
+//! generator, which memoises into a caller-provided buffer and never allocates.
+//! Everything else is built only with the "std" feature, enabled by default.
+//!
+//! Beyond the rational integers, the [gaussian](gaussian/index.html) and [eisenstein](eisenstein/index.html)
+//! modules provide the respective algebraic integers with their primality tests; factorization
+//! into primes needs the "std" feature.
+//!
+//! The [benchmark](benchmark/index.html) module provides a timing harness which works on stable,
+//! used by the `bench` mode of the binary. The [verify](verify/index.html) module cross-checks the
+//! generators against each other and against published values.
+//! The "ffi" feature additionally exports a C ABI over `PrimesMemoed`, see the [ffi](ffi/index.html) module;
+//! the `nth_prime_ffi` package builds it into static and dynamic libraries.
 
-use std::num::NonZeroU32;
+use core::num::NonZeroU32;
+
+#[cfg(feature = "std")]
+pub mod benchmark;
+mod buffered;
+pub mod eisenstein;
+#[cfg(feature = "std")]
+pub mod factorize;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod gaussian;
+pub mod miller_rabin;
+#[cfg(feature = "std")]
+pub mod verify;
+
+pub use crate::buffered::PrimesBuffered;
 
 /// Basic function to generate the nth prime number. Uses [Primes](struct.Primes.html) as default generator.
 ///
@@ -20,6 +52,33 @@ pub fn nth(n: u32) -> u32 {
         .into()
 }
 
+/// Number of primes which fit in the u32 range, i.e. pi(2^32).
+///
+/// Any index not below this one has no corresponding prime for the generators.
+pub const PRIMES_IN_U32: u32 = 203_280_221;
+
+/// Checks whether the provided number is prime.
+///
+/// Unlike [PrimeGenerator::predicate](trait.PrimeGenerator.html#method.predicate), this function gives
+/// the correct answer for every input, including 0 and 1, and only tries divisors up to the square root.
+pub fn is_prime(num: u32) -> bool {
+    match num {
+        0 | 1 => false,
+        2 | 3 => true,
+        _ if num.is_multiple_of(2) => false,
+        _ => (3..)
+            .step_by(2)
+            .take_while(|div: &u32| u64::from(*div) * u64::from(*div) <= u64::from(num))
+            .all(|div| !num.is_multiple_of(div)),
+    }
+}
+
+/// Integer square root, if the number is a perfect square.
+pub(crate) fn exact_sqrt(num: u64) -> Option<u64> {
+    let root = num.isqrt();
+    Some(root).filter(|r| r * r == num)
+}
+
 /// General implementation for the prime numbers generating structures.
 pub trait PrimeGenerator {
     /// Preducate function to check whether the number is prime.
@@ -27,7 +86,7 @@ pub trait PrimeGenerator {
     /// Default implementation iterates through all numbers from 2 to num - 1 and checks if num is divisible
     /// by the current number. Generators can override this function for speed.
     fn predicate(&self, num: u32) -> bool {
-        num > 2 && (2..num - 1).any(|n| num % n == 0)
+        num > 2 && (2..num - 1).any(|n| num.is_multiple_of(n))
     }
     /// Getter function to retrieve the first prime larger then provided number.
     ///
@@ -40,18 +99,21 @@ pub trait PrimeGenerator {
     /// Pure getter function to retrieve the first prime larger then provided number.
     ///
     /// Implementors should typically use the default implementation of this function, which
-    /// simply creates the range from the passed number to [std::u32::max_value()](https://doc.rust-lang.org/std/primitive.u32.html#method.max_value),
-    /// skips the non-primes (based on [predicate](#method.predicate)) and calls [next()](https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next).
+    /// simply creates the range from the passed number to [u32::MAX](https://doc.rust-lang.org/std/primitive.u32.html#associatedconstant.MAX)
+    /// and [finds](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.find) the first number passing the [predicate](#method.predicate).
     fn next_after_pure(&mut self, last: u32) -> Option<NonZeroU32> {
-        (last + 1..=u32::max_value())
-            .skip_while(|n| self.predicate(*n))
-            .next()
+        (last.checked_add(1)?..=u32::MAX)
+            .find(|n| !self.predicate(*n))
             .and_then(NonZeroU32::new)
     }
-    /// Function which generates the nth prime value.
+    /// Function which generates the nth prime value, counting from zero.
     /// 
-    /// By default, it simply delegates the work to [nth_after](#method.nth_after) method.
+    /// By default, it simply delegates the work to [nth_after](#method.nth_after) method,
+    /// returning None right away if n is not below [PRIMES_IN_U32](constant.PRIMES_IN_U32.html).
     fn nth(&mut self, n: u32) -> Option<NonZeroU32> {
+        if n >= PRIMES_IN_U32 {
+            return None;
+        }
         self.nth_after(n, 2)
     }
     /// Function that generates the nth prime value larger then provided number.
@@ -69,12 +131,56 @@ pub trait PrimeGenerator {
 /// For further information, refer to the documentation on the trait itself.
 pub struct Primes;
 impl Primes {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Primes
     }
 }
+impl Default for Primes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 impl PrimeGenerator for Primes {}
 
+/// Generator which checks the candidates with the [Miller-Rabin test](miller_rabin/index.html).
+///
+/// It doesn't keep any state, so it's as cheap to create as [Primes](struct.Primes.html),
+/// but it's much faster for large numbers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrimesMillerRabin;
+impl PrimesMillerRabin {
+    pub fn new() -> Self {
+        PrimesMillerRabin
+    }
+}
+impl PrimeGenerator for PrimesMillerRabin {
+    fn predicate(&self, num: u32) -> bool {
+        !miller_rabin::is_prime(u64::from(num))
+    }
+}
+
+/// Names of the generators available in this build, in the order they should be reported.
+#[cfg(feature = "std")]
+pub fn generator_names() -> Vec<&'static str> {
+    let mut names = vec!["default", "miller-rabin"];
+    if cfg!(feature = "memoed") {
+        names.push("memoed");
+    }
+    names
+}
+
+/// Creates a fresh generator by its name, as listed by [generator_names](fn.generator_names.html).
+#[cfg(feature = "std")]
+pub fn generator(name: &str) -> Option<Box<dyn PrimeGenerator>> {
+    match name {
+        "default" => Some(Box::new(Primes::new())),
+        "miller-rabin" => Some(Box::new(PrimesMillerRabin::new())),
+        #[cfg(feature = "memoed")]
+        "memoed" => Some(Box::new(PrimesMemoed::new())),
+        _ => None,
+    }
+}
+
 #[cfg(feature = "memoed")]
 #[derive(Debug, Clone)]
 pub struct PrimesMemoed {
@@ -90,6 +196,40 @@ impl PrimesMemoed {
     }
 }
 
+#[cfg(feature = "memoed")]
+impl Default for PrimesMemoed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "memoed")]
+impl PrimesMemoed {
+    /// Checks whether the provided number is prime, using the memoised primes as divisors.
+    ///
+    /// The memo is extended up to the square root of the number first, so the result is
+    /// correct regardless of what was generated before.
+    pub fn is_prime(&mut self, num: u32) -> bool {
+        if num < 2 {
+            return false;
+        }
+        let covers = |memo: &[u32]| {
+            memo.last()
+                .is_some_and(|&last| u64::from(last) * u64::from(last) >= u64::from(num))
+        };
+        while !covers(&self.memo) {
+            let last = *self.memo.last().expect("PrimesMemoed structure is corrupted - memo vector is empty");
+            if self.next_after(last).is_none() {
+                break;
+            }
+        }
+        self.memo
+            .iter()
+            .take_while(|&&div| u64::from(div) * u64::from(div) <= u64::from(num))
+            .all(|&div| !num.is_multiple_of(div))
+    }
+}
+
 #[cfg(feature = "memoed")]
 impl PrimeGenerator for PrimesMemoed {
     fn predicate(&self, num: u32) -> bool {
@@ -98,7 +238,7 @@ impl PrimeGenerator for PrimesMemoed {
                 .memo
                 .iter()
                 .cloned()
-                .any(|div| num % div == 0)
+                .any(|div| num.is_multiple_of(div))
     }
     fn next_after(&mut self, last: u32) -> Option<NonZeroU32> {
         let next = self.next_after_pure(last);
@@ -110,11 +250,14 @@ impl PrimeGenerator for PrimesMemoed {
         next
     }
     fn nth(&mut self, n: u32) -> Option<NonZeroU32> {
+        if n >= PRIMES_IN_U32 {
+            return None;
+        }
         let n = n as usize;
-        match self.memo.get(n - 1).cloned() {
+        match self.memo.get(n).cloned() {
             Some(val) => NonZeroU32::new(val),
             None => self.nth_after(
-                (n - self.memo.len()) as u32,
+                (n + 1 - self.memo.len()) as u32,
                 self.memo
                     .last()
                     .cloned()
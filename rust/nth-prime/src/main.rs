@@ -1,7 +1,13 @@
+use nth_prime::benchmark::{self, BenchConfig};
+use nth_prime::verify;
 #[cfg(feature = "memoed")]
 use nth_prime::{PrimesMemoed, PrimeGenerator};
+use std::env;
+use std::process;
+#[cfg(feature = "memoed")]
 use std::time::Instant;
 
+#[cfg(feature = "memoed")]
 macro_rules! nth {
     ($pm:expr, [$($nth:expr),+]) => {
         let mut pm = $pm;
@@ -15,14 +21,163 @@ macro_rules! nth {
     };
 }
 
+const USAGE: &str = "\
+Usage:
+    nth_prime                  run the memoisation demo (requires the \"memoed\" feature)
+    nth_prime bench [options]  time the generators and print the results as JSON
+    nth_prime verify [options] check the generators against each other and published values
+
+Bench options:
+    --generators <a,b,...>     generators to measure (default: all available)
+    --ns <n,m,...>             prime indices to compute (default: 10,100,1000)
+    --warmup <count>           untimed runs before measuring (default: 3)
+    --runs <count>             timed runs (default: 15)
+
+Verify options:
+    --generators <a,b,...>     generators to check (default: all available)
+    --indices <a..b|n,m,...>   prime indices to compare (default: 0..1000)
+    --range <low>,<high>       inclusive value range to compare (default: 0,10000)
+    --known <k>                check pi(10^i) and p(10^i) for i up to k (default: 3)";
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| fail(&format!("invalid value \"{}\" for {}", value, flag)))
+}
+
+fn parse_bench_args(args: &[String]) -> BenchConfig {
+    let mut config = BenchConfig::default();
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .unwrap_or_else(|| fail(&format!("missing value for {}", flag)));
+        match flag.as_str() {
+            "--generators" => config.generators = value.split(',').map(String::from).collect(),
+            "--ns" => config.ns = value.split(',').map(|n| parse_number(flag, n)).collect(),
+            "--warmup" => config.warmup = parse_number(flag, value),
+            "--runs" => config.runs = parse_number(flag, value),
+            _ => fail(&format!("unknown option {}", flag)),
+        }
+    }
+    config
+}
+
+/// Parses either an exclusive `a..b` range or a comma-separated list.
+fn parse_indices(flag: &str, value: &str) -> Vec<u32> {
+    match value.find("..") {
+        Some(split) => {
+            let low: u32 = parse_number(flag, &value[..split]);
+            let high: u32 = parse_number(flag, &value[split + 2..]);
+            if low > high {
+                fail(&format!("reversed range \"{}\" for {}", value, flag));
+            }
+            (low..high).collect()
+        }
+        None => value.split(',').map(|n| parse_number(flag, n)).collect(),
+    }
+}
+
+struct VerifyConfig {
+    generators: Vec<String>,
+    indices: Vec<u32>,
+    range: (u32, u32),
+    known: u32,
+}
+
+fn parse_verify_args(args: &[String]) -> VerifyConfig {
+    let mut config = VerifyConfig {
+        generators: nth_prime::generator_names().into_iter().map(String::from).collect(),
+        indices: (0..1000).collect(),
+        range: (0, 10000),
+        known: 3,
+    };
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .unwrap_or_else(|| fail(&format!("missing value for {}", flag)));
+        match flag.as_str() {
+            "--generators" => config.generators = value.split(',').map(String::from).collect(),
+            "--indices" => config.indices = parse_indices(flag, value),
+            "--range" => match value.split_once(',') {
+                Some((low, high)) => config.range = (parse_number(flag, low), parse_number(flag, high)),
+                None => fail(&format!("expected <low>,<high> for {}", flag)),
+            },
+            "--known" => config.known = parse_number(flag, value),
+            _ => fail(&format!("unknown option {}", flag)),
+        }
+    }
+    config
+}
+
+fn verify(args: &[String]) {
+    let config = parse_verify_args(args);
+    let names: Vec<&str> = config.generators.iter().map(String::as_str).collect();
+    let mut ok = true;
+    if names.len() < 2 {
+        println!("comparison skipped: only {} generator(s) selected", names.len());
+    } else {
+        let (low, high) = config.range;
+        let comparisons = vec![
+            ("indices", verify::compare_indices_by_name(&names, &config.indices)),
+            ("range", verify::compare_range_by_name(&names, low, high)),
+        ];
+        for (what, result) in comparisons {
+            match result {
+                Ok(None) => println!("{}: all generators agree", what),
+                Ok(Some(divergence)) => {
+                    ok = false;
+                    println!("{}: {}", what, divergence);
+                }
+                Err(err) => fail(&err.to_string()),
+            }
+        }
+    }
+    for name in &names {
+        let checks = verify::check_known(name, config.known).unwrap_or_else(|err| fail(&err.to_string()));
+        for check in checks {
+            ok &= check.passed();
+            println!("{}: {}", name, check);
+        }
+    }
+    if !ok {
+        process::exit(1);
+    }
+}
+
+fn bench(args: &[String]) {
+    let config = parse_bench_args(args);
+    match benchmark::run(&config) {
+        Ok(report) => println!("{}", report.to_json()),
+        Err(err) => fail(&err.to_string()),
+    }
+}
+
 #[cfg(feature = "memoed")]
-fn main() {
+fn demo() {
     nth!(PrimesMemoed::new(), [2, 5, 10, 50, 1000, 5000, 10000, 50000, 100000, 1000, 5000, 10000, 50000, 100000]);
     println!("Clearing pimes generator");
     nth!(PrimesMemoed::new(), [100000, 50000, 100001]);
 }
 
 #[cfg(not(feature = "memoed"))]
+fn demo() {
+    fail("Use \"--features memoed\" to run the demo");
+}
+
 fn main() {
-    panic!("Use \"--features memoed\"");
-}
\ No newline at end of file
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => demo(),
+        Some("bench") => bench(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("-h") | Some("--help") => println!("{}", USAGE),
+        Some(other) => fail(&format!("unknown mode \"{}\"", other)),
+    }
+}
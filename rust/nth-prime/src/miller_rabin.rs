@@ -0,0 +1,62 @@
+//! Deterministic Miller-Rabin primality test.
+//!
+//! Testing against the first twelve primes as witnesses is known to be exact for every
+//! 64-bit number, and the first three suffice for 32-bit ones. The modular arithmetic
+//! is done in u128, so nothing here allocates or needs the standard library.
+
+/// Witnesses which make the test exact below 2^32.
+const WITNESSES_32: [u64; 3] = [2, 7, 61];
+
+/// Witnesses which make the test exact below 2^64.
+const WITNESSES_64: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+pub(crate) fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(modulus)) as u64
+}
+
+pub(crate) fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Checks whether `witness` proves `num` composite, where `num - 1 = odd * 2^twos`.
+fn is_witness(witness: u64, num: u64, odd: u64, twos: u32) -> bool {
+    let mut x = pow_mod(witness, odd, num);
+    if x == 1 || x == num - 1 {
+        return false;
+    }
+    for _ in 1..twos {
+        x = mul_mod(x, x, num);
+        if x == num - 1 {
+            return false;
+        }
+    }
+    true
+}
+
+/// Checks whether the provided number is prime. Exact for every u64.
+pub fn is_prime(num: u64) -> bool {
+    if num < 2 {
+        return false;
+    }
+    let witnesses: &[u64] = if num < 1 << 32 { &WITNESSES_32 } else { &WITNESSES_64 };
+    for &p in witnesses {
+        if num == p {
+            return true;
+        }
+        if num.is_multiple_of(p) {
+            return false;
+        }
+    }
+    let twos = (num - 1).trailing_zeros();
+    let odd = (num - 1) >> twos;
+    !witnesses.iter().any(|&w| is_witness(w, num, odd, twos))
+}
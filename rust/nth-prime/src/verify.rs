@@ -0,0 +1,300 @@
+//! Consistency checks between the prime generators.
+//!
+//! The generators are given as instances, or looked up by name (see [generator_names](../fn.generator_names.html)),
+//! run side by side over the same indices or values, and the first point where they disagree is reported
+//! together with the last few values they agreed on. Independently, any single generator can be
+//! checked against the published values of pi(10^k) and p(10^k).
+
+use crate::{generator, PrimeGenerator};
+use std::fmt::{self, Display, Formatter};
+
+/// Number of agreed values kept as context for a divergence.
+const CONTEXT: usize = 3;
+
+/// Published values of pi(10^k), the number of primes not exceeding 10^k, for k = 1, 2, ...
+pub const PI_POWERS_OF_TEN: [u32; 9] = [4, 25, 168, 1_229, 9_592, 78_498, 664_579, 5_761_455, 50_847_534];
+
+/// Published values of p(10^k), the 10^k-th prime counting from one, for k = 1, 2, ...
+pub const P_POWERS_OF_TEN: [u32; 8] = [
+    29,
+    541,
+    7_919,
+    104_729,
+    1_299_709,
+    15_485_863,
+    179_424_673,
+    2_038_074_743,
+];
+
+/// Location in the compared sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// Zero-based prime index, as passed to `nth`.
+    Index(u32),
+    /// Zero-based offset among the primes of the checked range.
+    InRange(usize),
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Position::Index(n) => write!(f, "index {}", n),
+            Position::InRange(i) => write!(f, "prime #{} in range", i),
+        }
+    }
+}
+
+/// First disagreement between the generators.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    pub position: Position,
+    /// Value produced by every generator at this position, None if it produced nothing.
+    pub values: Vec<(String, Option<u32>)>,
+    /// Last values all generators agreed on, oldest first.
+    pub context: Vec<(Position, u32)>,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "generators disagree at {}:", self.position)?;
+        for (name, value) in &self.values {
+            match value {
+                Some(value) => write!(f, " {} = {};", name, value)?,
+                None => write!(f, " {} = none;", name)?,
+            }
+        }
+        if self.context.is_empty() {
+            write!(f, " no preceding values")
+        } else {
+            write!(f, " preceded by")?;
+            for (position, value) in &self.context {
+                write!(f, " [{}: {}]", position, value)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Which published sequence a known value belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnownKind {
+    /// pi(10^k)
+    Pi,
+    /// p(10^k)
+    P,
+}
+
+/// Result of checking a generator against a published value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownCheck {
+    pub kind: KnownKind,
+    pub exponent: u32,
+    pub expected: u32,
+    pub actual: Option<u32>,
+}
+
+impl KnownCheck {
+    pub fn passed(&self) -> bool {
+        self.actual == Some(self.expected)
+    }
+}
+
+impl Display for KnownCheck {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let name = match self.kind {
+            KnownKind::Pi => "pi",
+            KnownKind::P => "p",
+        };
+        write!(f, "{}(10^{}) = ", name, self.exponent)?;
+        match self.actual {
+            Some(actual) if actual == self.expected => write!(f, "{} ok", actual),
+            Some(actual) => write!(f, "{}, expected {}", actual, self.expected),
+            None => write!(f, "none, expected {}", self.expected),
+        }
+    }
+}
+
+/// Error returned when a verification can't be run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    UnknownGenerator(String),
+    NotEnoughGenerators,
+    InvalidRange(u32, u32),
+    ExponentTooLarge(u32),
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            VerifyError::UnknownGenerator(name) => write!(f, "unknown generator \"{}\"", name),
+            VerifyError::NotEnoughGenerators => write!(f, "at least two generators are needed for a comparison"),
+            VerifyError::InvalidRange(low, high) => write!(f, "invalid range {}..{}", low, high),
+            VerifyError::ExponentTooLarge(k) => write!(f, "no published values are known for 10^{}", k),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+type Named = (String, Box<dyn PrimeGenerator>);
+
+/// Generator under comparison, together with the name it's reported by.
+pub type NamedGenerator<'a> = (&'a str, &'a mut dyn PrimeGenerator);
+
+fn instantiate(names: &[&str], min: usize) -> Result<Vec<Named>, VerifyError> {
+    if names.len() < min {
+        return Err(VerifyError::NotEnoughGenerators);
+    }
+    names
+        .iter()
+        .map(|&name| {
+            generator(name)
+                .map(|primes| (name.to_string(), primes))
+                .ok_or_else(|| VerifyError::UnknownGenerator(name.to_string()))
+        })
+        .collect()
+}
+
+/// Borrows the instantiated generators in the form the comparisons take.
+fn borrow_named(generators: &mut [Named]) -> Vec<NamedGenerator<'_>> {
+    generators
+        .iter_mut()
+        .map(|(name, primes)| (name.as_str(), primes.as_mut() as &mut dyn PrimeGenerator))
+        .collect()
+}
+
+/// Walks the positions in order, asking every generator for its value, and stops at the first disagreement.
+fn first_divergence<P, F>(generators: &mut [NamedGenerator], positions: P, mut value: F) -> Option<Divergence>
+where
+    P: IntoIterator<Item = Position>,
+    F: FnMut(usize, &mut dyn PrimeGenerator, Position) -> Option<u32>,
+{
+    let mut context = Vec::with_capacity(CONTEXT + 1);
+    for position in positions {
+        let values: Vec<(String, Option<u32>)> = generators
+            .iter_mut()
+            .enumerate()
+            .map(|(i, (name, primes))| (name.to_string(), value(i, &mut **primes, position)))
+            .collect();
+        let first = values[0].1;
+        if values.iter().any(|(_, v)| *v != first) {
+            return Some(Divergence {
+                position,
+                values,
+                context,
+            });
+        }
+        match first {
+            Some(agreed) => {
+                context.push((position, agreed));
+                if context.len() > CONTEXT {
+                    context.remove(0);
+                }
+            }
+            // All generators are exhausted at once, so they agree on everything that remains.
+            None => break,
+        }
+    }
+    None
+}
+
+/// Runs the generators over the indices, in the given order, and reports the first disagreement.
+///
+/// Each generator instance is reused for all indices, so stateful generators are checked as well.
+/// Any generator can take part, including ones which aren't available by name.
+pub fn compare_indices(generators: &mut [NamedGenerator], indices: &[u32]) -> Result<Option<Divergence>, VerifyError> {
+    if generators.len() < 2 {
+        return Err(VerifyError::NotEnoughGenerators);
+    }
+    Ok(first_divergence(
+        generators,
+        indices.iter().map(|&n| Position::Index(n)),
+        |_, primes, position| match position {
+            Position::Index(n) => primes.nth(n).map(u32::from),
+            Position::InRange(_) => unreachable!("only indices are compared here"),
+        },
+    ))
+}
+
+/// Same as [compare_indices](fn.compare_indices.html), with fresh generators looked up by name.
+pub fn compare_indices_by_name(names: &[&str], indices: &[u32]) -> Result<Option<Divergence>, VerifyError> {
+    let mut generators = instantiate(names, 2)?;
+    compare_indices(&mut borrow_named(&mut generators), indices)
+}
+
+/// Runs the generators over the values in `[low, high]` and reports the first disagreement
+/// between the primes they produce there.
+///
+/// The generators are stepped with `next_after` from 2, so stateful generators see the same
+/// sequence of calls as they would during normal use.
+pub fn compare_range(generators: &mut [NamedGenerator], low: u32, high: u32) -> Result<Option<Divergence>, VerifyError> {
+    if low > high {
+        return Err(VerifyError::InvalidRange(low, high));
+    }
+    if generators.len() < 2 {
+        return Err(VerifyError::NotEnoughGenerators);
+    }
+    let sequences: Vec<Vec<u32>> = generators
+        .iter_mut()
+        .map(|(_, primes)| primes_in(&mut **primes, low, high))
+        .collect();
+    let longest = sequences.iter().map(Vec::len).max().unwrap_or(0);
+    Ok(first_divergence(
+        generators,
+        (0..longest).map(Position::InRange),
+        |i, _, position| match position {
+            Position::InRange(offset) => sequences[i].get(offset).cloned(),
+            Position::Index(_) => unreachable!("only range offsets are compared here"),
+        },
+    ))
+}
+
+/// Same as [compare_range](fn.compare_range.html), with fresh generators looked up by name.
+pub fn compare_range_by_name(names: &[&str], low: u32, high: u32) -> Result<Option<Divergence>, VerifyError> {
+    if low > high {
+        return Err(VerifyError::InvalidRange(low, high));
+    }
+    let mut generators = instantiate(names, 2)?;
+    compare_range(&mut borrow_named(&mut generators), low, high)
+}
+
+/// Collects the primes in `[low, high]`, as produced by the generator stepping from 2.
+fn primes_in(primes: &mut dyn PrimeGenerator, low: u32, high: u32) -> Vec<u32> {
+    let mut found = Vec::new();
+    let mut current = Some(2);
+    while let Some(prime) = current.filter(|&p| p <= high) {
+        if prime >= low {
+            found.push(prime);
+        }
+        current = primes.next_after(prime).map(u32::from);
+    }
+    found
+}
+
+/// Checks a single generator against the published values of pi(10^k) and p(10^k), for k from 1 up to `max_exponent`.
+pub fn check_known(name: &str, max_exponent: u32) -> Result<Vec<KnownCheck>, VerifyError> {
+    if max_exponent as usize > PI_POWERS_OF_TEN.len() {
+        return Err(VerifyError::ExponentTooLarge(max_exponent));
+    }
+    let mut checks = Vec::new();
+    for k in 1..=max_exponent {
+        let mut primes = instantiate(&[name], 1)?.remove(0).1;
+        let power = 10u32.pow(k);
+        checks.push(KnownCheck {
+            kind: KnownKind::Pi,
+            exponent: k,
+            expected: PI_POWERS_OF_TEN[k as usize - 1],
+            actual: Some(primes_in(primes.as_mut(), 0, power).len() as u32),
+        });
+        if let Some(&expected) = P_POWERS_OF_TEN.get(k as usize - 1) {
+            let mut primes = instantiate(&[name], 1)?.remove(0).1;
+            checks.push(KnownCheck {
+                kind: KnownKind::P,
+                exponent: k,
+                expected,
+                actual: primes.nth(power - 1).map(u32::from),
+            });
+        }
+    }
+    Ok(checks)
+}
@@ -0,0 +1,195 @@
+use nth_prime::eisenstein::Eisenstein;
+use nth_prime::gaussian::{Gaussian, GaussianPrimes};
+
+#[test]
+fn test_gaussian_arithmetic() {
+    let z = Gaussian::new(3, -2);
+    assert_eq!(z.norm(), 13);
+    assert_eq!(z * z.conj(), Gaussian::from(13));
+    assert_eq!(Gaussian::I * Gaussian::I, -Gaussian::ONE);
+    assert_eq!(Gaussian::new(1, 2) * Gaussian::new(3, 4), Gaussian::new(-5, 10));
+    assert_eq!(z.to_string(), "3 - 2i");
+    assert_eq!(Gaussian::new(0, 5).to_string(), "5i");
+}
+
+#[test]
+fn test_gaussian_division() {
+    let (a, b) = (Gaussian::new(27, 23), Gaussian::new(8, 1));
+    let (q, r) = a.div_rem(b);
+    assert_eq!(q * b + r, a);
+    assert!(2 * r.norm() <= b.norm());
+    assert_eq!(Gaussian::new(-5, 10).checked_div(Gaussian::new(1, 2)), Some(Gaussian::new(3, 4)));
+    assert_eq!(Gaussian::new(5, 0).checked_div(Gaussian::new(1, 3)), None);
+    assert_eq!(Gaussian::new(5, 0).gcd(Gaussian::new(3, 1)), Gaussian::new(1, 2));
+}
+
+#[test]
+fn test_gaussian_primality() {
+    let primes = [Gaussian::new(1, 1), Gaussian::new(2, -1), Gaussian::new(3, 0), Gaussian::new(0, -7)];
+    let composites = [Gaussian::ZERO, Gaussian::I, Gaussian::new(2, 0), Gaussian::new(5, 0), Gaussian::new(3, 3)];
+    assert!(primes.iter().all(|p| p.is_prime()));
+    assert!(!composites.iter().any(|c| c.is_prime()));
+}
+
+#[test]
+fn test_gaussian_primes_by_norm() {
+    let first: Vec<_> = GaussianPrimes::new().take(8).map(|z| (z.re, z.im)).collect();
+    assert_eq!(first, vec![(1, 1), (2, 1), (1, 2), (3, 0), (3, 2), (2, 3), (4, 1), (1, 4)]);
+}
+
+#[test]
+fn test_gaussian_primes_are_primes() {
+    let mut last_norm = 0;
+    for p in GaussianPrimes::new().take(500) {
+        assert!(p.is_prime(), "{}", p);
+        assert_eq!(p, p.normalized());
+        assert!(p.norm() >= last_norm);
+        last_norm = p.norm();
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_gaussian_factorization() {
+    for re in -40..=40 {
+        for im in -40..=40 {
+            let z = Gaussian::new(re, im);
+            match z.factorize() {
+                None => assert_eq!(z, Gaussian::ZERO),
+                Some(f) => {
+                    assert_eq!(f.product(), z);
+                    assert!(f.unit.is_unit());
+                    assert!(f.factors.iter().all(|&(p, _)| p.is_prime() && p == p.normalized()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gaussian_extremes() {
+    let z = Gaussian::new(i32::MIN, i32::MIN);
+    assert_eq!(z.norm(), 1 << 63);
+    assert!(!z.is_prime());
+    assert_eq!(Gaussian::new(i32::MIN, i32::MAX).norm(), (1 << 62) + (i32::MAX as u64).pow(2));
+    assert_eq!(z.checked_div(Gaussian::new(1, 1)), Some(Gaussian::new(i32::MIN, 0)));
+    assert_eq!(z.checked_div(Gaussian::new(-1, 0)), None);
+    let (quotient, rem) = z.div_rem(Gaussian::new(3, 0));
+    assert!(rem.norm() * 2 <= 9);
+    // quotient * 3 + rem, widened since the product doesn't fit in i32.
+    assert_eq!(i64::from(quotient.re) * 3 + i64::from(rem.re), i64::from(z.re));
+    assert_eq!(i64::from(quotient.im) * 3 + i64::from(rem.im), i64::from(z.im));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_gaussian_factorization_at_extremes() {
+    let f = Gaussian::new(i32::MIN, i32::MIN).factorize().unwrap();
+    assert_eq!(f.factors, vec![(Gaussian::new(1, 1), 63)]);
+    for &z in &[Gaussian::new(i32::MIN, i32::MAX), Gaussian::new(i32::MAX, i32::MAX)] {
+        let f = z.factorize().unwrap();
+        assert!(f.unit.is_unit());
+        let norm: u128 = f.factors.iter().map(|&(p, k)| u128::from(p.norm()).pow(k)).product();
+        assert_eq!(norm, u128::from(z.norm()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_gaussian_factorization_of_large_norm() {
+    let z = Gaussian::new(2_000_000_011, 1_999_999_973);
+    let f = z.factorize().unwrap();
+    assert_eq!(f.product(), z);
+}
+
+#[test]
+fn test_eisenstein_arithmetic() {
+    let omega = Eisenstein::OMEGA;
+    assert_eq!(omega * omega * omega, Eisenstein::ONE);
+    assert_eq!(omega * omega + omega + Eisenstein::ONE, Eisenstein::ZERO);
+    assert_eq!(Eisenstein::new(2, 1).norm(), 3);
+    let z = Eisenstein::new(5, -3);
+    assert_eq!(z * z.conj(), Eisenstein::from(z.norm() as i32));
+    assert_eq!(z.to_string(), "5 - 3ω");
+}
+
+#[test]
+fn test_eisenstein_units() {
+    assert!(Eisenstein::UNITS.iter().all(|u| u.is_unit()));
+    let rotation = Eisenstein::UNITS[1];
+    for (i, &u) in Eisenstein::UNITS.iter().enumerate() {
+        assert_eq!(u * rotation, Eisenstein::UNITS[(i + 1) % 6]);
+    }
+}
+
+#[test]
+fn test_eisenstein_division() {
+    let (a, b) = (Eisenstein::new(31, -17), Eisenstein::new(4, 7));
+    let (q, r) = a.div_rem(b);
+    assert_eq!(q * b + r, a);
+    assert!(4 * r.norm() <= 3 * b.norm());
+    assert_eq!(Eisenstein::new(7, 0).gcd(Eisenstein::new(3, 1)), Eisenstein::new(3, 1).normalized());
+}
+
+#[test]
+fn test_eisenstein_primality() {
+    let primes = [Eisenstein::new(2, 0), Eisenstein::new(2, 1), Eisenstein::new(1, -1), Eisenstein::new(3, 1), Eisenstein::new(0, 5)];
+    let composites = [Eisenstein::ZERO, Eisenstein::OMEGA, Eisenstein::new(3, 0), Eisenstein::new(7, 0), Eisenstein::new(4, 2)];
+    assert!(primes.iter().all(|p| p.is_prime()));
+    assert!(!composites.iter().any(|c| c.is_prime()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_eisenstein_factorization() {
+    for a in -40..=40 {
+        for b in -40..=40 {
+            let z = Eisenstein::new(a, b);
+            match z.factorize() {
+                None => assert_eq!(z, Eisenstein::ZERO),
+                Some(f) => {
+                    assert_eq!(f.product(), z);
+                    assert!(f.unit.is_unit());
+                    assert!(f.factors.iter().all(|&(p, _)| p.is_prime() && p == p.normalized()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_eisenstein_extremes() {
+    let z = Eisenstein::new(i32::MIN, i32::MAX);
+    let (a, b) = (i128::from(i32::MIN), i128::from(i32::MAX));
+    assert_eq!(u128::from(z.norm()), (a * a - a * b + b * b) as u128);
+    assert!(!Eisenstein::new(i32::MIN, i32::MIN).is_prime());
+    assert_eq!(Eisenstein::new(i32::MIN, i32::MIN).norm(), 1 << 62);
+    assert_eq!(z.checked_div(Eisenstein::ONE), Some(z));
+    let (quotient, rem) = z.div_rem(Eisenstein::new(5, 2));
+    assert!(rem.norm() * 4 <= Eisenstein::new(5, 2).norm() * 3);
+    // quotient * (5 + 2ω) + rem, widened since the product doesn't fit in i32.
+    let (qa, qb) = (i64::from(quotient.a), i64::from(quotient.b));
+    assert_eq!(5 * qa - 2 * qb + i64::from(rem.a), i64::from(z.a));
+    assert_eq!(2 * qa + 3 * qb + i64::from(rem.b), i64::from(z.b));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_eisenstein_factorization_at_extremes() {
+    for &z in &[Eisenstein::new(i32::MIN, i32::MAX), Eisenstein::new(i32::MIN, i32::MIN), Eisenstein::new(i32::MAX, i32::MIN + 1)] {
+        let f = z.factorize().unwrap();
+        assert!(f.unit.is_unit());
+        let norm: u128 = f.factors.iter().map(|&(p, k)| u128::from(p.norm()).pow(k)).product();
+        assert_eq!(norm, u128::from(z.norm()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_eisenstein_factorization_of_rational_primes() {
+    let seven = Eisenstein::from(7).factorize().unwrap();
+    assert_eq!(seven.factors.len(), 2);
+    assert!(seven.factors.iter().all(|&(p, k)| p.norm() == 7 && k == 1));
+    let five = Eisenstein::from(5).factorize().unwrap();
+    assert_eq!(five.factors, vec![(Eisenstein::from(5), 1)]);
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+use nth_prime::benchmark::{self, BenchConfig, BenchError, Stats};
+
+#[test]
+fn test_stats_of_single_sample() {
+    let stats = Stats::from_samples(&[7]).unwrap();
+    assert_eq!((stats.min, stats.median, stats.p99, stats.max, stats.mean), (7, 7, 7, 7, 7));
+}
+
+#[test]
+fn test_stats_use_nearest_rank() {
+    let samples: Vec<u64> = (1..=10).rev().collect();
+    let stats = Stats::from_samples(&samples).unwrap();
+    assert_eq!(stats.min, 1);
+    assert_eq!(stats.median, 5);
+    assert_eq!(stats.p90, 9);
+    assert_eq!(stats.p99, 10);
+    assert_eq!(stats.max, 10);
+    assert_eq!(stats.mean, 5);
+}
+
+#[test]
+fn test_stats_of_no_samples() {
+    assert_eq!(Stats::from_samples(&[]), None);
+}
+
+#[test]
+fn test_run_reports_every_combination() {
+    let config = BenchConfig {
+        generators: vec!["default".into()],
+        ns: vec![0, 5],
+        warmup: 0,
+        runs: 2,
+    };
+    let report = benchmark::run(&config).unwrap();
+    let primes: Vec<_> = report.measurements.iter().map(|m| (m.n, m.prime)).collect();
+    assert_eq!(primes, vec![(0, Some(2)), (5, Some(13))]);
+    let json = report.to_json();
+    assert!(json.starts_with("{\"version\":"));
+    assert!(json.contains("\"config\":{\"generators\":[\"default\"],\"ns\":[0,5],\"warmup\":0,\"runs\":2}"));
+    assert!(json.contains("{\"generator\":\"default\",\"n\":5,\"prime\":13,\"nanos\":{\"min\":"));
+}
+
+#[test]
+fn test_run_rejects_unknown_generator() {
+    let config = BenchConfig {
+        generators: vec!["sieve".into()],
+        ..BenchConfig::default()
+    };
+    assert_eq!(benchmark::run(&config), Err(BenchError::UnknownGenerator("sieve".into())));
+}
+
+#[test]
+fn test_run_rejects_zero_runs() {
+    let config = BenchConfig {
+        runs: 0,
+        ..BenchConfig::default()
+    };
+    assert_eq!(benchmark::run(&config), Err(BenchError::NoRuns));
+}
@@ -0,0 +1,62 @@
+use nth_prime::{miller_rabin, PrimeGenerator, Primes, PrimesBuffered, PrimesMillerRabin};
+
+#[test]
+fn test_miller_rabin_small_numbers() {
+    let primes: Vec<u64> = (0..50).filter(|&n| miller_rabin::is_prime(n)).collect();
+    assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]);
+}
+
+#[test]
+fn test_miller_rabin_agrees_with_trial_division() {
+    for n in 0..20_000u32 {
+        assert_eq!(miller_rabin::is_prime(u64::from(n)), nth_prime::is_prime(n), "{}", n);
+    }
+}
+
+#[test]
+fn test_miller_rabin_strong_pseudoprimes() {
+    // Strong pseudoprimes to the bases 2, 3, 5 and 7 respectively (or several of them).
+    for &n in &[2047u64, 1_373_653, 25_326_001, 3_215_031_751, 3_825_123_056_546_413_051] {
+        assert!(!miller_rabin::is_prime(n), "{}", n);
+    }
+}
+
+#[test]
+fn test_miller_rabin_large_primes() {
+    assert!(miller_rabin::is_prime(4_294_967_291));
+    assert!(miller_rabin::is_prime(18_446_744_073_709_551_557));
+    assert!(!miller_rabin::is_prime(u64::MAX));
+}
+
+#[test]
+fn test_miller_rabin_generator() {
+    assert_eq!(PrimesMillerRabin::new().nth(10000).map(u32::from), Some(104743));
+}
+
+#[test]
+fn test_buffered_memoises_up_to_capacity() {
+    let mut buffer = [0; 4];
+    let mut primes = PrimesBuffered::new(&mut buffer);
+    assert_eq!(primes.nth(10).map(u32::from), Some(31));
+    assert!(primes.is_full());
+    assert_eq!(primes.memoised(), &[2, 3, 5, 7]);
+    assert_eq!(primes.nth(2).map(u32::from), Some(5));
+}
+
+#[test]
+fn test_buffered_falls_back_past_capacity() {
+    let mut buffer = [0; 3];
+    let mut primes = PrimesBuffered::new(&mut buffer);
+    assert_eq!(primes.nth(1000).map(u32::from), Some(Primes::new().nth(1000).unwrap().into()));
+    // 7 is past the memoised [2, 3, 5], so these need the fallback.
+    assert!(primes.predicate(7 * 7 * 7 * 7));
+    assert!(!primes.predicate(7919));
+}
+
+#[test]
+fn test_buffered_with_empty_buffer() {
+    let mut primes = PrimesBuffered::new(&mut []);
+    assert_eq!(primes.nth(0).map(u32::from), Some(2));
+    assert_eq!(primes.nth(5).map(u32::from), Some(13));
+    assert!(primes.memoised().is_empty());
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "ffi")]
+
+use nth_prime::ffi::*;
+use std::ptr;
+
+#[test]
+fn test_nth_is_zero_based() {
+    let mut out = 0;
+    assert_eq!(unsafe { np_nth(0, &mut out) }, NP_OK);
+    assert_eq!(out, 2);
+    assert_eq!(unsafe { np_nth(10000, &mut out) }, NP_OK);
+    assert_eq!(out, 104743);
+}
+
+#[test]
+fn test_null_out_pointer() {
+    assert_eq!(unsafe { np_nth(1, ptr::null_mut()) }, NP_ERR_NULL_POINTER);
+    assert_eq!(unsafe { np_is_prime(1, ptr::null_mut()) }, NP_ERR_NULL_POINTER);
+}
+
+#[test]
+fn test_overflow_is_reported() {
+    let handle = np_primes_new();
+    let mut out = 0;
+    assert_eq!(unsafe { np_primes_nth(handle, u32::MAX, &mut out) }, NP_ERR_OVERFLOW);
+    unsafe { np_primes_free(handle) };
+}
+
+#[test]
+fn test_handle_is_prime_without_prior_generation() {
+    let handle = np_primes_new();
+    let mut out = -1;
+    assert_eq!(unsafe { np_primes_is_prime(handle, 9, &mut out) }, NP_OK);
+    assert_eq!(out, 0);
+    assert_eq!(unsafe { np_primes_is_prime(handle, 7919, &mut out) }, NP_OK);
+    assert_eq!(out, 1);
+    unsafe { np_primes_free(handle) };
+}
+
+#[test]
+fn test_range_reports_required_length() {
+    let handle = np_primes_new();
+    let mut buf = [0; 2];
+    let mut len = 0;
+    assert_eq!(
+        unsafe { np_primes_range(handle, 10, 30, buf.as_mut_ptr(), buf.len(), &mut len) },
+        NP_ERR_BUFFER_TOO_SMALL
+    );
+    assert_eq!((len, buf), (6, [11, 13]));
+    assert_eq!(
+        unsafe { np_primes_range(handle, 30, 10, ptr::null_mut(), 0, &mut len) },
+        NP_ERR_INVALID_RANGE
+    );
+    unsafe { np_primes_free(handle) };
+}
@@ -0,0 +1,149 @@
+#![cfg(feature = "std")]
+
+use nth_prime::verify::{self, Divergence, KnownKind, Position, VerifyError};
+use nth_prime::{PrimeGenerator, Primes};
+use std::num::NonZeroU32;
+use std::process::Command;
+
+/// Deliberately wrong generator, which leaves out one prime.
+struct Skipping(u32);
+
+impl PrimeGenerator for Skipping {
+    fn next_after(&mut self, last: u32) -> Option<NonZeroU32> {
+        match Primes.next_after(last) {
+            Some(prime) if u32::from(prime) == self.0 => Primes.next_after(self.0),
+            prime => prime,
+        }
+    }
+}
+
+#[test]
+fn test_known_values_of_default_generator() {
+    let checks = verify::check_known("default", 2).unwrap();
+    let summary: Vec<_> = checks.iter().map(|c| (c.kind, c.exponent, c.actual)).collect();
+    assert_eq!(
+        summary,
+        vec![
+            (KnownKind::Pi, 1, Some(4)),
+            (KnownKind::P, 1, Some(29)),
+            (KnownKind::Pi, 2, Some(25)),
+            (KnownKind::P, 2, Some(541)),
+        ]
+    );
+    assert!(checks.iter().all(|c| c.passed()));
+}
+
+#[test]
+fn test_known_exponent_is_bounded() {
+    assert_eq!(verify::check_known("default", 10), Err(VerifyError::ExponentTooLarge(10)));
+}
+
+#[test]
+fn test_comparison_needs_two_generators() {
+    assert_eq!(verify::compare_indices_by_name(&["default"], &[1, 2]), Err(VerifyError::NotEnoughGenerators));
+}
+
+#[test]
+fn test_unknown_generator() {
+    assert_eq!(
+        verify::compare_range_by_name(&["default", "sieve"], 0, 10),
+        Err(VerifyError::UnknownGenerator("sieve".into()))
+    );
+}
+
+#[test]
+fn test_reversed_range() {
+    assert_eq!(
+        verify::compare_range_by_name(&["default", "default"], 10, 0),
+        Err(VerifyError::InvalidRange(10, 0))
+    );
+}
+
+#[test]
+fn test_identical_generators_agree() {
+    assert_eq!(verify::compare_indices_by_name(&["default", "default"], &[0, 5, 3, 100]), Ok(None));
+    assert_eq!(verify::compare_range_by_name(&["default", "default"], 0, 1000), Ok(None));
+}
+
+#[test]
+fn test_divergence_at_index() {
+    let (mut correct, mut wrong) = (Primes::new(), Skipping(23));
+    let indices: Vec<u32> = (0..12).collect();
+    let divergence = verify::compare_indices(&mut [("default", &mut correct), ("skips-23", &mut wrong)], &indices)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        divergence,
+        Divergence {
+            position: Position::Index(8),
+            values: vec![("default".into(), Some(23)), ("skips-23".into(), Some(29))],
+            context: vec![(Position::Index(5), 13), (Position::Index(6), 17), (Position::Index(7), 19)],
+        }
+    );
+    assert_eq!(
+        divergence.to_string(),
+        "generators disagree at index 8: default = 23; skips-23 = 29; preceded by [index 5: 13] [index 6: 17] [index 7: 19]"
+    );
+}
+
+#[test]
+fn test_divergence_in_range() {
+    let (mut correct, mut wrong) = (Primes::new(), Skipping(7));
+    let divergence = verify::compare_range(&mut [("skips-7", &mut wrong), ("default", &mut correct)], 2, 30)
+        .unwrap()
+        .unwrap();
+    assert_eq!(divergence.position, Position::InRange(3));
+    assert_eq!(divergence.values, vec![("skips-7".into(), Some(11)), ("default".into(), Some(7))]);
+    assert_eq!(
+        divergence.context,
+        vec![(Position::InRange(0), 2), (Position::InRange(1), 3), (Position::InRange(2), 5)]
+    );
+}
+
+#[test]
+fn test_divergence_when_a_generator_runs_out() {
+    let (mut correct, mut wrong) = (Primes::new(), Skipping(7));
+    let divergence = verify::compare_range(&mut [("default", &mut correct), ("skips-7", &mut wrong)], 6, 10)
+        .unwrap()
+        .unwrap();
+    assert_eq!(divergence.values, vec![("default".into(), Some(7)), ("skips-7".into(), None)]);
+    assert_eq!(
+        divergence.to_string(),
+        "generators disagree at prime #0 in range: default = 7; skips-7 = none; no preceding values"
+    );
+}
+
+#[test]
+fn test_instances_need_two_generators() {
+    let mut primes = Primes::new();
+    assert_eq!(
+        verify::compare_range(&mut [("default", &mut primes)], 0, 10),
+        Err(VerifyError::NotEnoughGenerators)
+    );
+}
+
+#[test]
+fn test_binary_rejects_reversed_indices() {
+    let output = Command::new(env!("CARGO_BIN_EXE_nth_prime"))
+        .args(["verify", "--indices", "100..10"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("reversed range \"100..10\" for --indices"));
+}
+
+#[test]
+fn test_position_display() {
+    assert_eq!(Position::Index(7).to_string(), "index 7");
+    assert_eq!(Position::InRange(2).to_string(), "prime #2 in range");
+}
+
+#[cfg(feature = "memoed")]
+#[test]
+fn test_memoed_agrees_with_default() {
+    let indices: Vec<u32> = (0..300).chain(vec![10, 0, 500]).collect();
+    assert_eq!(verify::compare_indices_by_name(&["default", "memoed"], &indices), Ok(None));
+    assert_eq!(verify::compare_range_by_name(&["memoed", "default"], 100, 5000), Ok(None));
+    assert!(verify::check_known("memoed", 3).unwrap().iter().all(|c| c.passed()));
+}